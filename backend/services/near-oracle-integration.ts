@@ -159,11 +159,13 @@ export class NEAROracleIntegration {
         contractId: this.contractId,
         methodName: 'submit_evaluation',
         args: {
-          intent_id: intentId,
-          answer: evaluationResult.answer,
-          confidence: evaluationResult.confidence,
-          sources: evaluationResult.sources,
-          execution_time_ms: { $numberLong: evaluationResult.execution_time.toString() },
+          input: {
+            intent_id: intentId,
+            answer: evaluationResult.answer,
+            confidence: evaluationResult.confidence,
+            sources: evaluationResult.sources,
+            execution_time_ms: { $numberLong: evaluationResult.execution_time.toString() },
+          },
         },
         attachedDeposit: BigInt(this.solverConfig.minStakeAmount),
         gas: BigInt('50000000000000'), // 50 TGas
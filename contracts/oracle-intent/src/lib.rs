@@ -1,32 +1,249 @@
 use near_sdk::{
-    env, near, require, AccountId, Promise, Gas, NearToken,
-    collections::{LookupMap, UnorderedMap, Vector},
+    env, near, require, AccountId, Promise, PromiseOrValue, PromiseResult, Gas, NearToken,
+    collections::{LookupMap, TreeMap, UnorderedMap, Vector},
     serde::{Deserialize, Serialize},
     json_types::{U128, U64},
     BorshStorageKey,
 };
 use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
 // use schemars::JsonSchema;
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::ops::Bound;
 
 // Type alias for compatibility
 type Balance = u128;
 
 const TGAS: u64 = 1_000_000_000_000;
 const MIN_STAKE: Balance = 1_000_000_000_000_000_000_000_000; // 1 NEAR
-const SETTLEMENT_GAS: Gas = Gas::from_tgas(50);
+const DEFAULT_MAX_REWARD: Balance = 1_000 * MIN_STAKE; // 1,000 NEAR
 const MAX_SOURCES_PER_EVALUATION: usize = 15;
 const MAX_QUESTION_LENGTH: usize = 500;
+const MAX_ENCRYPTED_QUESTION_LENGTH: usize = 2_048;
 const MAX_URL_LENGTH: usize = 200;
+const MAX_CATEGORY_LENGTH: usize = 50;
+const MAX_TEMPLATE_NAME_LENGTH: usize = 50;
+const MAX_CONTRIBUTORS: usize = 10;
+const MAX_PREFERENCE_CATEGORIES: usize = 10;
+const MAX_PREFERENCE_LANGUAGES: usize = 10;
+const MAX_LANGUAGE_TAG_LENGTH: usize = 35;
+const MAX_REQUIRED_CONFIRMATIONS: u32 = 10;
+const MAX_ALLOWED_SOLVERS: usize = 50; // longest per-intent solver allowlist
+const MAX_QUORUM_EVALUATIONS: usize = 25; // evaluations a quorum intent accepts before it stops taking more
+const DEFAULT_MAX_ACTIVE_EVALUATIONS_PER_SOLVER: u32 = 20;
+const DEFAULT_MIN_EVALUATIONS_FOR_POOL: u64 = 1;
+const CONTRIBUTION_WEIGHT_TOLERANCE: f64 = 0.001; // allowed drift of contribution weights from 1.0
+// Largest page returned by paginated views. Each intent costs a storage read plus
+// Borsh and JSON (de)serialization; 100 per page stays well inside the 200 TGas view budget.
+const MAX_PAGE_LIMIT: u64 = 100;
+const AUTO_CLEANUP_RETENTION_DAYS: u64 = 30;
+const NANOS_PER_YEAR: f64 = 365.0 * 86_400_000_000_000.0;
+const DEFAULT_MIN_DEADLINE_MINUTES: u64 = 5;
+const DEFAULT_MAX_DEADLINE_MINUTES: u64 = 7 * 24 * 60; // longest an intent may stay open, including extensions
+const DEFAULT_DEADLINE_MINUTES: u64 = 60;
+const DEFAULT_SLASH_FRACTION_BPS: u16 = 1_000; // 10% of registration stake
+const DEFAULT_MAX_SOURCE_DOMAIN_SHARE_BPS: u16 = 5_000; // at most half the sources from one host
+const DEFAULT_CHALLENGE_WITHDRAWAL_PENALTY_BPS: u16 = 500; // 5% of the challenge stake
+const DEFAULT_CHALLENGE_STAKE_MULTIPLIER: f64 = 1.5;
+const DEFAULT_SPEED_BONUS_CAP_BPS: u16 = 1_000; // up to 10% for the fastest evaluations
+const DEFAULT_SPEED_BONUS_TARGET_MS: u64 = 60_000; // no bonus from one minute of execution time
+const DEFAULT_CORROBORATION_BONUS_BPS: u16 = 200; // 2% for relying mostly on well-corroborated sources
+const MAX_CORROBORATION_BONUS_BPS: u16 = 1_000;
+const DEFAULT_CORROBORATION_MIN_COUNT: u64 = 3; // confirmed evaluations citing a source before it counts as corroborated
+const HIGH_VALUE_INTENT_STAKE_MULTIPLE: Balance = 5; // rewards above this many min stakes need a Gold solver
+const VERIFIER_REWARD_BPS: u128 = 1_000; // share of the losing stake paid to majority voters
+const VERIFIER_SLASH_MIN_VOTES: u64 = 5; // votes before a low agreement rate is slashed
+const MIN_CHALLENGE_PERIOD: u64 = 3_600_000_000_000; // 1 hour in nanoseconds
+const MAX_CHALLENGE_PERIOD: u64 = 7 * 86_400_000_000_000; // 7 days in nanoseconds
+const BPS_DENOMINATOR: u128 = 10_000;
+const DEFAULT_STALL_TIMEOUT: u64 = 2 * 3_600_000_000_000; // 2 hours in nanoseconds
+const DEFAULT_VESTING_PERIOD: u64 = 30 * 86_400_000_000_000; // 30 days in nanoseconds
+const DEFAULT_VESTING_TRANCHES: u32 = 4;
+const MAX_VESTING_TRANCHES: u32 = 52;
+const DEFAULT_ADMIN_ACTION_DELAY: u64 = 24 * 3_600_000_000_000; // 1 day in nanoseconds
+const MIN_ADMIN_ACTION_DELAY: u64 = 3_600_000_000_000; // 1 hour in nanoseconds
+const DEFAULT_FORCE_EXPIRE_TIMEOUT: u64 = 24 * 3_600_000_000_000; // 1 day past the deadline, in nanoseconds
+const DEFAULT_DISPUTE_SETTLEMENT_DEADLINE: u64 = 7 * 24 * 3_600_000_000_000; // 7 days from the challenge, in nanoseconds
+const STALL_UPTIME_PENALTY: f64 = 0.8; // uptime_score multiplier for abandoning an accepted intent
+// Solvers are expected to call `heartbeat` about once an hour; uptime_score averages over the last day
+const HEARTBEAT_INTERVAL: u64 = 3_600_000_000_000; // 1 hour in nanoseconds
+const UPTIME_WINDOW_INTERVALS: f64 = 24.0;
+const DEFAULT_DISPUTE_COOLDOWN: u64 = 3_600_000_000_000; // 1 hour in nanoseconds
+const DEFAULT_REPUTATION_RESET_MIN_STAKE: Balance = 5 * MIN_STAKE;
+const DEFAULT_REPUTATION_RESET_COOLDOWN: u64 = 30 * 86_400_000_000_000; // 30 days in nanoseconds
+const DEFAULT_REPUTATION_HALF_LIFE: u64 = 30 * 86_400_000_000_000; // 30 days in nanoseconds
+// Neutral prior blended into reputation so a handful of results can't produce an extreme score
+const DEFAULT_REPUTATION_PRIOR_MEAN: f64 = 0.5;
+const DEFAULT_REPUTATION_PRIOR_WEIGHT: f64 = 2.0;
+// Cap on how many minimum-value results a single high-value intent counts as
+const MAX_REPUTATION_IMPACT_WEIGHT: f64 = 10.0;
+const FT_TRANSFER_GAS: Gas = Gas::from_tgas(10);
+// Version 1 never shipped; launch state carries no version at all and is read by `migrate`
+const STATE_VERSION: u32 = 2; // bump together with `OldOracleIntentContract` and `migrate`
+const ONE_YOCTO: NearToken = NearToken::from_yoctonear(1);
+const REWARD_CALLBACK_GAS: Gas = Gas::from_gas(10 * TGAS);
+const PRICE_FEED_GAS: Gas = Gas::from_tgas(10);
+const PRICE_CALLBACK_GAS: Gas = Gas::from_tgas(15);
 
-#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+/// Stable error codes for the validation paths clients most often need to branch on: 1xxx
+/// solver registration, 2xxx evaluation submission, 3xxx challenges. Codes are never reused.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(u16)]
+pub enum OracleError {
+    AccountBlacklisted = 1001,
+    InsufficientRegistrationDeposit = 1002,
+    SolverAlreadyRegistered = 1003,
+
+    InsufficientSolverStake = 2001,
+    InvalidConfidence = 2002,
+    InvalidPriceThreshold = 2003,
+    IntentNotFound = 2004,
+    IntentAssignedToAnotherSolver = 2005,
+    IntentNotInProgress = 2006,
+    IntentNotPending = 2007,
+    IntentExpired = 2008,
+    QuorumFull = 2009,
+    AlreadyEvaluated = 2010,
+    NotEnoughSources = 2011,
+    SolverNotRegistered = 2012,
+    SolverNotActive = 2013,
+    TooManyActiveEvaluations = 2014,
+    TooManyEvaluationsForIntent = 2015,
+
+    ChallengerNotRegistered = 3001,
+    ChallengerTierTooLow = 3002,
+    EvaluationNotFound = 3003,
+    ChallengeStakeBelowMultiple = 3004,
+    InsufficientChallengeStake = 3005,
+    CounterSourcesRequired = 3006,
+    EvaluationNotChallengeable = 3007,
+    DuplicateChallenge = 3008,
+    ChallengeStakeNotEscalated = 3009,
+    ChallengePeriodExpired = 3010,
+    ChallengerReputationTooLow = 3011,
+}
+
+impl OracleError {
+    pub fn code(self) -> u16 {
+        self as u16
+    }
+
+    pub fn message(self) -> &'static str {
+        match self {
+            OracleError::AccountBlacklisted => "Account is blacklisted",
+            OracleError::InsufficientRegistrationDeposit => "Insufficient deposit to cover the registration fee and stake",
+            OracleError::SolverAlreadyRegistered => "Solver is already registered; top up with add_solver_stake",
+            OracleError::InsufficientSolverStake => "Insufficient solver stake",
+            OracleError::InvalidConfidence => "Confidence must be between 0 and 1",
+            OracleError::InvalidPriceThreshold => "Price threshold must be a non-negative number",
+            OracleError::IntentNotFound => "Intent not found",
+            OracleError::IntentAssignedToAnotherSolver => "Intent is assigned to another solver",
+            OracleError::IntentNotInProgress => "Intent is not in progress",
+            OracleError::IntentNotPending => "Intent is not pending",
+            OracleError::IntentExpired => "Intent has expired",
+            OracleError::QuorumFull => "Intent is not accepting more evaluations",
+            OracleError::AlreadyEvaluated => "Solver has already evaluated this intent",
+            OracleError::NotEnoughSources => "Not enough sources for this intent",
+            OracleError::SolverNotRegistered => "Solver not registered",
+            OracleError::SolverNotActive => "Solver is not active",
+            OracleError::TooManyActiveEvaluations => "Solver has too many active evaluations",
+            OracleError::TooManyEvaluationsForIntent => "Intent has reached its evaluation limit",
+            OracleError::ChallengerNotRegistered => "Only registered solvers or verified users can submit challenges",
+            OracleError::ChallengerTierTooLow => "Silver tier or above required to submit challenges",
+            OracleError::EvaluationNotFound => "Evaluation not found",
+            OracleError::ChallengeStakeBelowMultiple => "Challenge stake is below the required multiple of the evaluation stake",
+            OracleError::InsufficientChallengeStake => "Insufficient stake for challenge",
+            OracleError::CounterSourcesRequired => "Counter sources required",
+            OracleError::EvaluationNotChallengeable => "Evaluation cannot be challenged",
+            OracleError::DuplicateChallenge => "Challenger already has an open challenge on this evaluation",
+            OracleError::ChallengeStakeNotEscalated => "Challenge stake must exceed the stake of earlier challengers",
+            OracleError::ChallengePeriodExpired => "Challenge period has expired",
+            OracleError::ChallengerReputationTooLow => "Challenger reputation is below the minimum required to challenge",
+        }
+    }
+
+    /// Abort with `ERR_<code>: <message>`
+    pub fn panic(self) -> ! {
+        env::panic_str(&format!("ERR_{}: {}", self.code(), self.message()))
+    }
+
+    /// Abort with `ERR_<code>: <message> (<detail>)`
+    pub fn panic_with(self, detail: &str) -> ! {
+        env::panic_str(&format!("ERR_{}: {} ({})", self.code(), self.message(), detail))
+    }
+}
+
+/// `require!` for structured errors: `ensure!(condition, OracleError::X)`, optionally followed
+/// by `format!` arguments for a detail appended to the message
+macro_rules! ensure {
+    ($condition:expr, $error:expr $(,)?) => {{
+        let condition: bool = $condition;
+        if !condition {
+            $error.panic()
+        }
+    }};
+    ($condition:expr, $error:expr, $($detail:tt)+) => {{
+        let condition: bool = $condition;
+        if !condition {
+            $error.panic_with(&format!($($detail)+))
+        }
+    }};
+}
+
+#[derive(BorshSerialize, BorshStorageKey)]
+enum StorageKey {
+    SolverStakes,
+    Quotes,
+    MinStakeByType,
+    DomainReliability,
+    SolverIds,
+    FailedTransfers,
+    ActiveEvaluationCounts,
+    PendingDeadlines,
+    SolverEvaluations,
+    SolverEvaluationIds { account_hash: Vec<u8> },
+    Commitments,
+    IntentStatusCounts,
+    EvaluationStatusCounts,
+    IntentTemplates,
+    PendingWithdrawals,
+    VerifierStakes,
+    VerifierRecords,
+    DisputeVotes,
+    SolverChallenges,
+    SolverChallengeIds { account_hash: Vec<u8> },
+    IntentHistory,
+    IntentHistoryEntries { intent_hash: Vec<u8> },
+    AreaPerformance,
+    ReputationArchives,
+    Blacklist,
+    Referrals,
+    ReferralStats,
+    AllowedDomains,
+    EvaluationChallenges,
+    EvaluationChallengeIds { evaluation_hash: Vec<u8> },
+    SolverPreferences,
+    AdminActions,
+    DuplicateLinks,
+    LinkedDuplicates,
+    VestingSchedule,
+    SolverHeartbeats,
+    SourceCorroboration,
+    IntentEvaluationCounts,
+    StakeLockedUntil,
+    OpenAppealCounts,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(crate = "near_sdk::serde")]
 pub struct Source {
     pub title: String,
     pub url: String,
+    // SHA-256 (hex) or IPFS CID of the content as fetched, so archived evidence can be re-verified
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_hash: Option<String>,
 }
 
-#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug)]
 #[serde(crate = "near_sdk::serde")]
 pub enum IntentType {
     CredibilityEvaluation,
@@ -34,6 +251,176 @@ pub enum IntentType {
     OracleSettlement,
 }
 
+/// Urgency of an intent; higher priorities require a larger stake
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Priority {
+    Low,
+    Normal,
+    High,
+    Critical,
+}
+
+impl Priority {
+    /// Multiple of the base minimum stake required at this priority
+    fn stake_multiplier(&self) -> u128 {
+        match self {
+            Priority::Low | Priority::Normal => 1,
+            Priority::High => 2,
+            Priority::Critical => 4,
+        }
+    }
+}
+
+/// Which collection a resumable cleanup sweep is walking
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+#[serde(crate = "near_sdk::serde")]
+pub enum CleanupPhase {
+    #[default]
+    Intents,
+    Challenges, // before evaluations, so each challenge's solver can still be found
+    Evaluations,
+}
+
+/// Position of a cleanup sweep; `cleanup_old_data_resumable` and `auto_cleanup` each keep one
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Copy, Default)]
+#[serde(crate = "near_sdk::serde")]
+pub struct CleanupCursor {
+    pub phase: CleanupPhase,
+    pub index: u64,
+}
+
+/// How far `migrate_legacy_records` has got; each count is the number of leading entries of
+/// that map already in the current layout
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Copy, Default)]
+#[serde(crate = "near_sdk::serde")]
+pub struct LegacyRecordMigration {
+    pub intents: u64,
+    pub evaluations: u64,
+    pub challenges: u64,
+}
+
+/// One step in an intent's lifecycle, as returned by `get_intent_history`
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct IntentHistoryEntry {
+    pub timestamp: U64,
+    pub actor: AccountId,
+    pub action: String,
+    pub status_after: IntentStatus,
+}
+
+/// Standing of a solver, derived from reputation and track record; unlocks privileges
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub enum ReputationTier {
+    Bronze,
+    Silver, // may submit challenges
+    Gold, // may accept high-value intents
+    Platinum,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TierRequirement {
+    pub min_reputation: f64,
+    pub min_evaluations: u64,
+}
+
+/// Owner-configurable requirements for each tier; every solver is at least Bronze
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TierThresholds {
+    pub silver: TierRequirement,
+    pub gold: TierRequirement,
+    pub platinum: TierRequirement,
+}
+
+impl Default for TierThresholds {
+    fn default() -> Self {
+        Self {
+            silver: TierRequirement { min_reputation: 0.5, min_evaluations: 0 },
+            gold: TierRequirement { min_reputation: 0.7, min_evaluations: 5 },
+            platinum: TierRequirement { min_reputation: 0.85, min_evaluations: 25 },
+        }
+    }
+}
+
+impl TierThresholds {
+    fn tier_for(&self, solver: &OracleSolver) -> ReputationTier {
+        let meets = |requirement: &TierRequirement| {
+            solver.reputation_score >= requirement.min_reputation
+                && solver.total_evaluations >= requirement.min_evaluations
+        };
+        if meets(&self.platinum) {
+            ReputationTier::Platinum
+        } else if meets(&self.gold) {
+            ReputationTier::Gold
+        } else if meets(&self.silver) {
+            ReputationTier::Silver
+        } else {
+            ReputationTier::Bronze
+        }
+    }
+}
+
+/// Who referred an account, and whether the referrer has been paid for it
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Referral {
+    pub referrer: AccountId,
+    pub bonus_paid: bool,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Default)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ReferralStats {
+    pub referral_count: u32,
+    pub rewarded_referrals: u32, // referred accounts that went on to a first success
+    pub total_bonus_earned: U128,
+}
+
+/// Which intents a solver wants an off-chain notification service to push to it. Purely
+/// advisory: the contract does not filter intents by these.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct SolverPreferences {
+    pub categories: Vec<String>, // subset of the solver's specialization areas; empty means any
+    pub min_reward: U128,
+    pub max_concurrent: u32, // intents the solver wants to work on at once
+    pub languages: Vec<String>, // lowercase BCP-47 tags the solver can answer in; empty means unstated
+}
+
+/// A sensitive operation run through the timelock queue. Queuing and executing an action
+/// need the same privilege as calling its entry point directly.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub enum AdminAction {
+    CleanupOldData { retention_days: u64, max_operations: u32 },
+    DistributePerformanceRewards { total_reward_pool: U128 },
+    SettleDispute { evaluation_id: String, challenge_id: String, winner: String },
+    SetAdminActionDelay { admin_action_delay: U64 }, // the only way to shorten the delay
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct QueuedAdminAction {
+    pub action_id: u64,
+    pub action: AdminAction,
+    pub proposer: AccountId,
+    pub queued_at: U64,
+    pub executable_at: U64,
+}
+
+/// A solver's track record within one intent category
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Default)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AreaPerformance {
+    pub evaluations: u64,
+    pub successes: u64,
+    pub avg_confidence: f64,
+}
+
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
 #[serde(crate = "near_sdk::serde")]
 pub struct OracleIntent {
@@ -48,6 +435,81 @@ pub struct OracleIntent {
     pub deadline: U64,
     pub status: IntentStatus,
     pub created_at: U64,
+    pub assigned_solver: Option<AccountId>,
+    pub category: Option<String>, // e.g., "financial", matched against solver specializations
+    pub confidence_threshold: Option<f64>, // confidence the initiator asked for; scales the payout
+    pub required_sources: u32, // minimum number of sources an evaluation must cite
+    pub priority: Priority,
+    pub challenge_period: Option<U64>, // overrides the global challenge period, nanoseconds
+    pub required_confirmations: u32, // agreeing evaluations needed to complete; above 1 the intent takes several
+    pub quorum_evaluations: Vec<String>, // evaluations submitted toward the quorum, in order
+    pub excluded_solvers: Vec<AccountId>, // solvers refuted on this intent; barred from answering it again
+    pub assigned_at: Option<U64>, // when assigned_solver took the intent on
+    pub encrypted_question: Option<String>, // private intents: the question encrypted for solvers off-chain
+    pub question_hash: Option<String>, // private intents: hex SHA-256 of the plaintext question
+    pub language: Option<String>, // lowercase BCP-47 tag of the question, e.g. "en" or "ja-jp"
+    pub allowed_solvers: Option<Vec<AccountId>>, // permissioned intents: only these solvers may answer
+}
+
+/// How close a quorum intent is to completion, as returned by `get_confirmation_status`
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ConfirmationStatus {
+    pub required_confirmations: u32,
+    pub confirming: u32, // evaluations agreeing with the leading answer
+    pub dissenting: u32,
+    pub quorum_reached: bool,
+}
+
+/// A solver's full standing in one call, as returned by `get_solver_dashboard`
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct SolverDashboard {
+    pub solver: OracleSolver,
+    pub tier: ReputationTier,
+    pub reputation: ReputationScores,
+    pub stake: U128,
+    pub active_evaluations: u32,
+    pub pending_withdrawal: U128,
+    pub cooldown_until: Option<U64>, // None when the solver may take on work
+    pub specialization_performance: Vec<(String, Option<AreaPerformance>)>, // per specialization area
+}
+
+/// A solver's earnings picture, as returned by `get_solver_roi`
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RoiStats {
+    pub total_rewards_earned: U128,
+    pub total_stakes_lost: U128,
+    pub net_profit: U128, // rewards minus lost stakes, or 0 when the solver is behind
+    pub net_loss: U128, // lost stakes minus rewards, or 0 when the solver is ahead
+    pub reward_to_stake_ratio: f64, // rewards earned per unit of registration stake
+    pub active_since: U64,
+    pub evaluations_per_year: f64,
+    pub annualized_return: f64, // net result per unit of stake, scaled to a year of activity
+}
+
+/// Everything needed to judge a dispute in one call, as returned by `get_dispute_details`
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct DisputeDetails {
+    pub challenge_id: String,
+    pub evaluation_id: String,
+    pub intent_id: String,
+    pub question: String,
+    pub solver: AccountId,
+    pub answer: bool,
+    pub answer_value: Option<AnswerValue>,
+    pub confidence: f64,
+    pub sources: Vec<Source>,
+    pub evaluation_stake: U128,
+    pub evaluation_status: EvaluationStatus,
+    pub challenger: AccountId,
+    pub counter_sources: Vec<Source>,
+    pub rebuttal_sources: Vec<Source>,
+    pub challenge_stake: U128,
+    pub challenge_status: ChallengeStatus,
+    pub submitted_at: U64,
 }
 
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, PartialEq)]
@@ -59,6 +521,25 @@ pub enum IntentStatus {
     Disputed,
     Settled,
     Expired,
+    Cancelled,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct SolverQuote {
+    pub solver: AccountId,
+    pub proposed_confidence: f64,
+    pub estimated_time_ms: U64,
+    pub bid_reward: Balance,
+    pub submitted_at: U64,
+}
+
+/// Sealed evaluation awaiting `reveal_evaluation`
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct EvaluationCommitment {
+    pub commitment: String, // hex sha256 of the reveal preimage
+    pub committed_at: U64,
 }
 
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
@@ -75,6 +556,48 @@ pub struct OracleEvaluation {
     pub stake: Balance,
     pub status: EvaluationStatus,
     pub submitted_at: U64,
+    pub reputation_counted: bool, // set once this evaluation has been folded into solver reputation
+    pub contributors: Vec<(AccountId, f64)>, // solver -> reward weight; empty when `solver` worked alone
+    pub amended_at: Option<U64>, // last substantive amendment; restarts the challenge period
+    pub price_threshold: Option<f64>, // numeric claim: `answer` asserts the price is at or above this
+    pub price_feed_result: Option<PriceFeedResult>,
+    pub answer_value: Option<AnswerValue>, // non-boolean answer; when None, `answer` is the result
+}
+
+/// A solver's answer: yes/no, a number (price, count, percentage) or one of a set of labels
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub enum AnswerValue {
+    Boolean(bool),
+    Numeric(f64),
+    Categorical(String),
+}
+
+impl AnswerValue {
+    /// Whether two answers agree; numeric answers agree when within `tolerance` of each other
+    fn agrees_with(&self, other: &AnswerValue, tolerance: f64) -> bool {
+        match (self, other) {
+            (AnswerValue::Numeric(a), AnswerValue::Numeric(b)) => (a - b).abs() <= tolerance,
+            _ => self == other,
+        }
+    }
+}
+
+impl OracleEvaluation {
+    fn answer_value(&self) -> AnswerValue {
+        self.answer_value.clone().unwrap_or(AnswerValue::Boolean(self.answer))
+    }
+}
+
+/// Outcome of checking an evaluation's price claim against an on-chain price feed
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PriceFeedResult {
+    pub feed_contract: AccountId,
+    pub pair: String,
+    pub price: f64,
+    pub agrees: bool, // whether the feed price supports the evaluation's answer
+    pub checked_at: U64,
 }
 
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, PartialEq)]
@@ -97,6 +620,11 @@ pub struct RefutationChallenge {
     pub stake: Balance,
     pub status: ChallengeStatus,
     pub submitted_at: U64,
+    pub rebuttal_sources: Vec<Source>, // evaluator's counter-refutation evidence
+    pub settled_at: Option<U64>,
+    pub settled_winner: Option<String>, // "evaluator", "challenger", "tie" or "partial"
+    pub settlement_payout: Balance, // amount paid to the winning party at settlement
+    pub appeal_votes: Vec<(AccountId, bool)>, // verifier -> uphold original outcome
 }
 
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, PartialEq)]
@@ -106,6 +634,8 @@ pub enum ChallengeStatus {
     UnderReview,
     Successful,
     Failed,
+    Appealed,
+    Withdrawn,
 }
 
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
@@ -118,6 +648,32 @@ pub struct OracleSolver {
     pub total_stake: Balance,
     pub is_active: bool,
     pub performance_metrics: SolverPerformanceMetrics,
+    pub decayed_successes: f64, // time-decayed weight of successful results
+    pub decayed_evaluations: f64, // time-decayed weight of all results
+    pub last_reputation_update: U64,
+    pub cooldown_until: U64, // after a lost dispute, no new work is accepted before this time
+    pub reputation_window_start: U64, // when the current reputation window began
+}
+
+/// A reputation window closed by `reset_reputation_window`
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ReputationWindow {
+    pub started_at: U64,
+    pub ended_at: U64,
+    pub reputation_score: f64,
+    pub decayed_successes: f64,
+    pub decayed_evaluations: f64,
+}
+
+/// A solver's reputation over the current window alongside their all-time record
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ReputationScores {
+    pub window_score: f64,
+    pub lifetime_score: f64,
+    pub window_started_at: U64,
+    pub archived_windows: u32,
 }
 
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
@@ -156,14 +712,167 @@ pub struct UserProfile {
     pub total_stake_committed: Balance,
 }
 
+/// Reputation- and confidence-weighted consensus across several intents' confirmed evaluations
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AggregatedResult {
+    pub outcome: bool,
+    pub combined_confidence: f64, // share of total weight backing the outcome
+    pub total_source_count: u64,
+    pub evaluations_counted: u32,
+    pub meets_threshold: bool,
+}
+
+/// Dimension used to rank solvers in `get_leaderboard`
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub enum LeaderboardMetric {
+    Reputation,
+    RewardsEarned,
+    Speed,
+    ChallengeDefenseRate,
+    UptimeScore,
+}
+
+/// Reusable question pattern with `{placeholder}` slots filled by `submit_intent_from_template`
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct IntentTemplate {
+    pub owner: AccountId,
+    pub name: String,
+    pub question_pattern: String,
+    pub required_sources: u32,
+    pub confidence_threshold: f64,
+    pub created_at: U64,
+}
+
+/// What an evaluation must meet for an intent. Answers below `confidence_threshold` are
+/// accepted but earn a proportionally reduced share of the reward.
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct IntentRequirements {
+    pub required_sources: u32,
+    pub confidence_threshold: Option<f64>,
+}
+
+/// Voting history of a verifier
+#[derive(BorshDeserialize, BorshSerialize, Default)]
+pub struct VerifierRecord {
+    pub votes_cast: u64,
+    pub majority_votes: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct VerifierStats {
+    pub stake: U128,
+    pub votes_cast: u64,
+    pub majority_votes: u64,
+    pub majority_agreement_rate: f64,
+}
+
+/// Network-wide totals maintained as running counters
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct NetworkStats {
+    pub intents_by_status: Vec<(IntentStatus, u64)>,
+    pub evaluations_by_status: Vec<(EvaluationStatus, u64)>,
+    pub active_solvers: u64,
+    pub total_value_staked: U128,
+    pub average_solver_reputation: f64,
+    pub total_rewards_distributed: U128,
+}
+
+/// Parameters of a credibility intent, shared by the native and NEP-141 submission paths
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct CredibilityIntentInput {
+    pub question: String,
+    pub required_sources: Option<u32>,
+    pub confidence_threshold: Option<f64>,
+    pub deadline_minutes: Option<u64>,
+    pub category: Option<String>,
+    pub priority: Option<Priority>,
+    pub challenge_period_minutes: Option<u64>,
+    pub target_reward: Option<U128>, // stake only this much of the deposit and refund the rest
+    pub required_confirmations: Option<u32>,
+    pub language: Option<String>, // BCP-47 tag of the question's language
+    pub allowed_solvers: Option<Vec<AccountId>>, // restrict answering to these solvers
+}
+
+/// One evaluation within a `submit_evaluations_batch` call
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct EvaluationInput {
+    pub intent_id: String,
+    pub answer: bool,
+    pub confidence: f64,
+    pub sources: Vec<Source>,
+    pub execution_time_ms: U64,
+    pub price_threshold: Option<f64>,
+    pub answer_value: Option<AnswerValue>, // overrides `answer` for numeric and categorical questions
+    pub question: Option<String>, // plaintext question, required when answering a private intent
+}
+
+/// Parameters of a private credibility intent; see `submit_private_credibility_intent`
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PrivateCredibilityIntentInput {
+    pub encrypted_question: String,
+    pub question_hash: String, // hex SHA-256 of the plaintext question
+    pub required_sources: Option<u32>,
+    pub confidence_threshold: Option<f64>,
+    pub deadline_minutes: Option<u64>,
+    pub category: Option<String>,
+    pub priority: Option<Priority>,
+    pub target_reward: Option<U128>,
+}
+
+/// The question of a private intent, as stored until a solver reveals it
+pub struct SealedQuestion {
+    pub encrypted_question: String,
+    pub question_hash: String,
+}
+
+/// Instruction carried in the `msg` of an `ft_transfer_call` to the stake token
+#[derive(Deserialize)]
+#[serde(crate = "near_sdk::serde", tag = "action", rename_all = "snake_case")]
+pub enum StakeTransferMessage {
+    RegisterSolver,
+    AddSolverStake,
+    SubmitCredibilityIntent(CredibilityIntentInput),
+    SubmitEvaluation(EvaluationInput),
+    SubmitEvaluationsBatch {
+        submissions: Vec<EvaluationInput>,
+    },
+    ExtendIntentDeadline {
+        intent_id: String,
+        additional_minutes: u64,
+    },
+    FundBonusPool,
+    SubmitChallenge {
+        evaluation_id: String,
+        counter_sources: Vec<Source>,
+    },
+}
+
 #[near(contract_state)]
 pub struct OracleIntentContract {
     pub owner: AccountId,
     pub intents: UnorderedMap<String, OracleIntent>,
     pub evaluations: UnorderedMap<String, OracleEvaluation>,
     pub challenges: UnorderedMap<String, RefutationChallenge>,
+    pub quotes: UnorderedMap<String, Vec<SolverQuote>>, // intent_id -> competing solver quotes
+    pub pending_deadlines: TreeMap<(u64, String), ()>, // (deadline, intent_id) expiry index; stale entries are skipped
     pub solvers: LookupMap<AccountId, OracleSolver>,
+    pub solver_ids: Vector<AccountId>, // iteration index over `solvers`
     pub solver_stakes: LookupMap<AccountId, Balance>,
+    pub solver_evaluations: LookupMap<AccountId, Vector<String>>, // solver -> evaluation IDs in submission order
+    pub commitments: LookupMap<(String, AccountId), EvaluationCommitment>, // (intent_id, solver) -> sealed answer
+    pub failed_transfers: LookupMap<AccountId, Balance>, // payouts that bounced, claimable by the recipient
+    pub active_evaluation_counts: LookupMap<AccountId, u32>, // solver -> evaluations Submitted or Challenged
+    pub max_active_evaluations_per_solver: u32,
+    pub domain_reliability: LookupMap<String, f64>, // URL host -> reliability score (0-1)
     pub users: LookupMap<AccountId, UserProfile>,
     pub admins: Vector<AccountId>,
     pub verifiers: Vector<AccountId>,
@@ -171,8 +880,177 @@ pub struct OracleIntentContract {
     pub evaluation_counter: u64,
     pub challenge_counter: u64,
     pub min_stake: Balance,
+    pub min_stake_by_type: LookupMap<IntentType, Balance>, // overrides min_stake per intent type
     pub max_evaluation_time: U64,
     pub challenge_period: U64, // nanoseconds
+    pub appeal_period: U64, // nanoseconds after settlement during which the loser may appeal
+    pub reveal_delay: U64, // nanoseconds a commitment must age before it can be revealed
+    pub reputation_half_life: U64, // nanoseconds for a past result to lose half its weight
+    pub slash_fraction_bps: u16, // share of registration stake slashed on a lost dispute
+    pub challenge_stake_multiplier: f64, // challenge stake must be at least this multiple of the evaluation stake
+    pub stake_token: Option<AccountId>, // NEP-141 token used for stakes and rewards; native NEAR when None
+    pub total_escrowed: Balance, // stakes held by the contract that are still owed to someone
+    pub bonus_pool: Balance, // owner-seeded funds backing reward bonuses; part of total_escrowed
+    pub require_specialization_match: bool, // solvers must list a categorized intent's category
+    pub min_challenge_period: U64, // lower bound for a per-intent challenge period, nanoseconds
+    pub max_challenge_period: U64, // upper bound for a per-intent challenge period, nanoseconds
+    pub paused: bool, // circuit breaker: user-facing state changes are rejected while set
+    pub pending_owner: Option<AccountId>, // proposed owner awaiting `accept_ownership`
+    pub stale_scan_cursor: u64, // next `solver_ids` index for `deactivate_stale_solvers`
+    pub intent_status_counts: LookupMap<IntentStatus, u64>, // running intent totals per status, for `get_network_stats`
+    pub evaluation_status_counts: LookupMap<EvaluationStatus, u64>, // running evaluation totals per status
+    pub active_solver_count: u64,
+    pub reputation_sum: f64, // sum of reputation_score over registered solvers
+    pub total_rewards_distributed: Balance, // lifetime evaluation rewards and dispute winnings paid
+    pub intent_templates: UnorderedMap<String, IntentTemplate>, // "{owner}:{name}" -> reusable intent template
+    pub pending_withdrawals: LookupMap<AccountId, Balance>, // rewards and refunds owed, paid out by `withdraw`
+    pub amendment_window: U64, // nanoseconds after submission during which a solver may amend
+    pub verifier_stakes: LookupMap<AccountId, Balance>, // stake posted by verifiers to vote on disputes
+    pub verifier_records: LookupMap<AccountId, VerifierRecord>,
+    pub dispute_votes: LookupMap<String, Vec<(AccountId, String)>>, // challenge_id -> (verifier, winner) votes
+    pub solver_challenges: LookupMap<AccountId, Vector<String>>, // evaluated solver -> challenge IDs in filing order
+    pub registration_fee: Balance, // non-refundable charge on solver registration, on top of min_stake
+    pub protocol_treasury: Balance, // collected registration and protocol fees; part of total_escrowed
+    pub tier_thresholds: TierThresholds, // requirements for each reputation tier above Bronze
+    pub intent_history: LookupMap<String, Vector<IntentHistoryEntry>>, // append-only lifecycle log per intent, for `get_intent_history`
+    pub min_evaluations_for_pool: u64, // settled evaluations a solver needs to share in `distribute_performance_rewards`
+    pub answer_tolerance: f64, // numeric answers this close to each other count as agreeing
+    pub dispute_cooldown: U64, // nanoseconds a solver sits out after losing a minimum-value dispute; scales with value
+    pub max_reward: Balance, // largest reward a single intent may carry
+    pub area_performance: LookupMap<(AccountId, String), AreaPerformance>, // per-solver results by intent category
+    pub protocol_fee_bps: u16, // share of each reward and dispute winning kept in protocol_treasury
+    pub reputation_archives: LookupMap<AccountId, Vec<ReputationWindow>>, // closed reputation windows per solver, oldest first
+    pub reputation_reset_min_stake: Balance, // stake a solver must hold to start a fresh reputation window
+    pub reputation_reset_cooldown: U64, // how long a reputation window must run before it can be reset
+    pub challenge_withdrawal_penalty_bps: u16, // share of a withdrawn challenge's stake kept in protocol_treasury
+    pub min_deadline_minutes: u64, // shortest deadline an intent may be created with
+    pub max_deadline_minutes: u64, // longest an intent may stay open, including extensions
+    pub blacklist: LookupMap<AccountId, String>, // banned solver accounts -> reason
+    pub referrals: LookupMap<AccountId, Referral>, // referred account -> who referred them
+    pub referral_stats: LookupMap<AccountId, ReferralStats>, // referrer -> referrals made and bonuses earned
+    pub referral_bonus: Balance, // paid from protocol_treasury when a referred account first succeeds
+    pub stall_timeout: U64, // how long an assigned solver has to submit before the intent can be reclaimed
+    pub allowed_domains: UnorderedMap<String, bool>, // source host -> allowed (true) or blocked (false)
+    pub enforce_domain_allowlist: bool, // when set, sources must come from an allowed domain
+    pub cleanup_cursor: CleanupCursor, // where `cleanup_old_data_resumable` picks up next
+    pub reputation_prior_mean: f64, // score a solver with no record is assumed to have
+    pub reputation_prior_weight: f64, // virtual results the prior counts for; higher keeps early scores steadier
+    pub require_source_diversity: bool, // when set, no single host may supply too large a share of an evaluation's sources
+    pub max_source_domain_share_bps: u16, // largest share of sources one host may supply under `require_source_diversity`
+    pub evaluation_challenges: LookupMap<String, Vector<String>>, // evaluation_id -> challenge ids, in submission order
+    pub speed_bonus_cap_bps: u16, // largest speed bonus, reached at zero execution time
+    pub speed_bonus_target_ms: u64, // execution time at or above which no speed bonus is paid
+    pub force_expire_timeout: U64, // how long past its deadline an intent must be before `force_expire_intent` may close it
+    pub solver_preferences: LookupMap<AccountId, SolverPreferences>, // which intents each solver wants to be notified about
+    pub dispute_winner_bps: u16, // share of the losing side's stake, after fees, paid to the dispute winner; the rest goes back to the loser
+    pub admin_actions: UnorderedMap<u64, QueuedAdminAction>, // time-locked admin actions awaiting execution
+    pub next_admin_action_id: u64,
+    pub admin_action_delay: U64, // how long a queued admin action waits before it may run
+    pub keeper_reward: Balance, // paid out of the intent reward to whoever triggers a finalization
+    pub intent_reveal_delay: U64, // nanoseconds after creation before solvers may claim, quote on or answer an intent
+    pub duplicate_links: LookupMap<String, String>, // duplicate intent_id -> the canonical intent it waits on
+    pub linked_duplicates: LookupMap<String, Vec<String>>, // canonical intent_id -> duplicates resolved with it
+    pub vesting_schedule: LookupMap<AccountId, Vec<(U64, Balance)>>, // unreleased reward tranches per account as (release time, amount), earliest first
+    pub vesting_threshold: Balance, // payouts above this vest instead of paying at once; 0 disables vesting
+    pub vesting_period: U64, // time over which a vested payout is released
+    pub vesting_tranches: u32, // equal tranches a vested payout is released in
+    pub min_challenger_reputation: f64, // reputation a solver needs to file challenges
+    pub min_challenger_verification_level: Option<u8>, // verification level at which non-solver users may file challenges; None bars them
+    pub dispute_settlement_deadline: U64, // how long after a challenge the owner has to settle it before `force_resolve_stale_dispute` may
+    pub solver_heartbeats: LookupMap<AccountId, U64>, // time of each solver's last `heartbeat` call
+    pub source_corroboration_count: LookupMap<String, u64>, // confirmed evaluations that cited each source URL
+    pub corroboration_bonus_bps: u16, // bonus for evaluations whose sources are mostly corroborated, paid from the bonus pool
+    pub corroboration_min_count: u64, // citations by confirmed evaluations that make a source corroborated
+    pub max_evaluations_per_intent: u32, // evaluations one intent accepts over its lifetime, relists and quorum included
+    pub intent_evaluation_counts: LookupMap<String, u32>, // evaluations submitted against each intent
+    pub stake_locked_until: LookupMap<AccountId, U64>, // end of the latest appeal window on a dispute each account took part in
+    pub open_appeal_counts: LookupMap<AccountId, u32>, // appealed disputes each account is a party to
+    pub auto_cleanup_cursor: CleanupCursor, // where `auto_cleanup` and `batch_process_expired_and_cleanup` pick up next
+    pub legacy_record_migration: Option<LegacyRecordMigration>, // set by `migrate` until every launch-layout record is upgraded
+    pub state_version: u32, // layout version of this struct; see `migrate`
+}
+
+/// Contract state as laid out at launch, read once by `migrate`. That layout predates
+/// `state_version`, so `migrate` cannot run twice: a current state does not parse as this.
+///
+/// When a release changes the layout of `OracleIntentContract`, replace this struct with a
+/// copy of the outgoing layout, map it into the new shape in `migrate`, bump `STATE_VERSION`,
+/// and call `migrate` right after deploying the new code. New fields go at the end of the
+/// struct so the shadow only ever differs by what was added or changed.
+#[derive(BorshDeserialize)]
+struct OldOracleIntentContract {
+    owner: AccountId,
+    intents: UnorderedMap<String, OracleIntent>, // values still in the `LegacyOracleIntent` layout
+    evaluations: UnorderedMap<String, OracleEvaluation>, // values still `LegacyOracleEvaluation`
+    challenges: UnorderedMap<String, RefutationChallenge>, // values still `LegacyRefutationChallenge`
+    solvers: LookupMap<AccountId, OracleSolver>,
+    _solver_stakes: LookupMap<AccountId, Balance>, // shared the `s` prefix with `solvers`; see `migrate_legacy_solvers`
+    users: LookupMap<AccountId, UserProfile>,
+    admins: Vector<AccountId>,
+    verifiers: Vector<AccountId>,
+    intent_counter: u64,
+    evaluation_counter: u64,
+    challenge_counter: u64,
+    min_stake: Balance,
+    max_evaluation_time: U64,
+    challenge_period: U64,
+}
+
+/// `Source` as stored at launch, before content hashes
+#[derive(BorshDeserialize, BorshSerialize)]
+struct LegacySource {
+    title: String,
+    url: String,
+}
+
+/// `OracleIntent` as stored at launch
+#[derive(BorshDeserialize, BorshSerialize)]
+struct LegacyOracleIntent {
+    intent_id: String,
+    intent_type: IntentType,
+    initiator: AccountId,
+    question: Option<String>,
+    evaluation_hash: Option<String>,
+    challenge_hash: Option<String>,
+    stake: Balance,
+    reward: Balance,
+    deadline: U64,
+    status: IntentStatus,
+    created_at: U64,
+}
+
+/// `OracleEvaluation` as stored at launch
+#[derive(BorshDeserialize, BorshSerialize)]
+struct LegacyOracleEvaluation {
+    evaluation_id: String,
+    intent_id: String,
+    solver: AccountId,
+    question: String,
+    answer: bool,
+    confidence: f64,
+    sources: Vec<LegacySource>,
+    execution_time: U64,
+    stake: Balance,
+    status: EvaluationStatus,
+    submitted_at: U64,
+}
+
+/// `RefutationChallenge` as stored at launch
+#[derive(BorshDeserialize, BorshSerialize)]
+struct LegacyRefutationChallenge {
+    challenge_id: String,
+    evaluation_id: String,
+    challenger: AccountId,
+    counter_sources: Vec<LegacySource>,
+    stake: Balance,
+    status: ChallengeStatus,
+    submitted_at: U64,
+}
+
+impl From<LegacySource> for Source {
+    fn from(legacy: LegacySource) -> Self {
+        Self { title: legacy.title, url: legacy.url, content_hash: None }
+    }
 }
 
 impl Default for OracleIntentContract {
@@ -182,8 +1060,17 @@ impl Default for OracleIntentContract {
             intents: UnorderedMap::new(b"i"),
             evaluations: UnorderedMap::new(b"e"),
             challenges: UnorderedMap::new(b"c"),
+            quotes: UnorderedMap::new(StorageKey::Quotes),
+            pending_deadlines: TreeMap::new(StorageKey::PendingDeadlines),
             solvers: LookupMap::new(b"s"),
-            solver_stakes: LookupMap::new(b"s"),
+            solver_ids: Vector::new(StorageKey::SolverIds),
+            solver_stakes: LookupMap::new(StorageKey::SolverStakes),
+            solver_evaluations: LookupMap::new(StorageKey::SolverEvaluations),
+            commitments: LookupMap::new(StorageKey::Commitments),
+            failed_transfers: LookupMap::new(StorageKey::FailedTransfers),
+            active_evaluation_counts: LookupMap::new(StorageKey::ActiveEvaluationCounts),
+            max_active_evaluations_per_solver: DEFAULT_MAX_ACTIVE_EVALUATIONS_PER_SOLVER,
+            domain_reliability: LookupMap::new(StorageKey::DomainReliability),
             users: LookupMap::new(b"u"),
             admins: Vector::new(b"a"),
             verifiers: Vector::new(b"v"),
@@ -191,8 +1078,94 @@ impl Default for OracleIntentContract {
             evaluation_counter: 0,
             challenge_counter: 0,
             min_stake: MIN_STAKE,
+            min_stake_by_type: LookupMap::new(StorageKey::MinStakeByType),
             max_evaluation_time: U64(300_000_000_000), // 5 minutes in nanoseconds
             challenge_period: U64(86_400_000_000_000), // 24 hours in nanoseconds
+            appeal_period: U64(86_400_000_000_000), // 24 hours in nanoseconds
+            reveal_delay: U64(60_000_000_000), // 1 minute in nanoseconds
+            reputation_half_life: U64(DEFAULT_REPUTATION_HALF_LIFE),
+            slash_fraction_bps: DEFAULT_SLASH_FRACTION_BPS,
+            challenge_stake_multiplier: DEFAULT_CHALLENGE_STAKE_MULTIPLIER,
+            stake_token: None,
+            total_escrowed: 0,
+            bonus_pool: 0,
+            require_specialization_match: false,
+            min_challenge_period: U64(MIN_CHALLENGE_PERIOD),
+            max_challenge_period: U64(MAX_CHALLENGE_PERIOD),
+            paused: false,
+            pending_owner: None,
+            stale_scan_cursor: 0,
+            intent_status_counts: LookupMap::new(StorageKey::IntentStatusCounts),
+            evaluation_status_counts: LookupMap::new(StorageKey::EvaluationStatusCounts),
+            active_solver_count: 0,
+            reputation_sum: 0.0,
+            total_rewards_distributed: 0,
+            intent_templates: UnorderedMap::new(StorageKey::IntentTemplates),
+            pending_withdrawals: LookupMap::new(StorageKey::PendingWithdrawals),
+            amendment_window: U64(300_000_000_000), // 5 minutes in nanoseconds
+            verifier_stakes: LookupMap::new(StorageKey::VerifierStakes),
+            verifier_records: LookupMap::new(StorageKey::VerifierRecords),
+            dispute_votes: LookupMap::new(StorageKey::DisputeVotes),
+            solver_challenges: LookupMap::new(StorageKey::SolverChallenges),
+            registration_fee: 0,
+            protocol_treasury: 0,
+            tier_thresholds: TierThresholds::default(),
+            intent_history: LookupMap::new(StorageKey::IntentHistory),
+            min_evaluations_for_pool: DEFAULT_MIN_EVALUATIONS_FOR_POOL,
+            answer_tolerance: 0.0,
+            dispute_cooldown: U64(DEFAULT_DISPUTE_COOLDOWN),
+            max_reward: DEFAULT_MAX_REWARD,
+            area_performance: LookupMap::new(StorageKey::AreaPerformance),
+            protocol_fee_bps: 0,
+            reputation_archives: LookupMap::new(StorageKey::ReputationArchives),
+            reputation_reset_min_stake: DEFAULT_REPUTATION_RESET_MIN_STAKE,
+            reputation_reset_cooldown: U64(DEFAULT_REPUTATION_RESET_COOLDOWN),
+            challenge_withdrawal_penalty_bps: DEFAULT_CHALLENGE_WITHDRAWAL_PENALTY_BPS,
+            min_deadline_minutes: DEFAULT_MIN_DEADLINE_MINUTES,
+            max_deadline_minutes: DEFAULT_MAX_DEADLINE_MINUTES,
+            blacklist: LookupMap::new(StorageKey::Blacklist),
+            referrals: LookupMap::new(StorageKey::Referrals),
+            referral_stats: LookupMap::new(StorageKey::ReferralStats),
+            referral_bonus: 0,
+            stall_timeout: U64(DEFAULT_STALL_TIMEOUT),
+            allowed_domains: UnorderedMap::new(StorageKey::AllowedDomains),
+            enforce_domain_allowlist: false,
+            cleanup_cursor: CleanupCursor::default(),
+            reputation_prior_mean: DEFAULT_REPUTATION_PRIOR_MEAN,
+            reputation_prior_weight: DEFAULT_REPUTATION_PRIOR_WEIGHT,
+            require_source_diversity: false,
+            max_source_domain_share_bps: DEFAULT_MAX_SOURCE_DOMAIN_SHARE_BPS,
+            evaluation_challenges: LookupMap::new(StorageKey::EvaluationChallenges),
+            speed_bonus_cap_bps: DEFAULT_SPEED_BONUS_CAP_BPS,
+            speed_bonus_target_ms: DEFAULT_SPEED_BONUS_TARGET_MS,
+            force_expire_timeout: U64(DEFAULT_FORCE_EXPIRE_TIMEOUT),
+            solver_preferences: LookupMap::new(StorageKey::SolverPreferences),
+            dispute_winner_bps: BPS_DENOMINATOR as u16,
+            admin_actions: UnorderedMap::new(StorageKey::AdminActions),
+            next_admin_action_id: 0,
+            admin_action_delay: U64(DEFAULT_ADMIN_ACTION_DELAY),
+            keeper_reward: 0,
+            intent_reveal_delay: U64(0),
+            duplicate_links: LookupMap::new(StorageKey::DuplicateLinks),
+            linked_duplicates: LookupMap::new(StorageKey::LinkedDuplicates),
+            vesting_schedule: LookupMap::new(StorageKey::VestingSchedule),
+            vesting_threshold: 0,
+            vesting_period: U64(DEFAULT_VESTING_PERIOD),
+            vesting_tranches: DEFAULT_VESTING_TRANCHES,
+            min_challenger_reputation: 0.0,
+            min_challenger_verification_level: None,
+            dispute_settlement_deadline: U64(DEFAULT_DISPUTE_SETTLEMENT_DEADLINE),
+            solver_heartbeats: LookupMap::new(StorageKey::SolverHeartbeats),
+            source_corroboration_count: LookupMap::new(StorageKey::SourceCorroboration),
+            corroboration_bonus_bps: DEFAULT_CORROBORATION_BONUS_BPS,
+            corroboration_min_count: DEFAULT_CORROBORATION_MIN_COUNT,
+            max_evaluations_per_intent: MAX_QUORUM_EVALUATIONS as u32,
+            intent_evaluation_counts: LookupMap::new(StorageKey::IntentEvaluationCounts),
+            stake_locked_until: LookupMap::new(StorageKey::StakeLockedUntil),
+            open_appeal_counts: LookupMap::new(StorageKey::OpenAppealCounts),
+            auto_cleanup_cursor: CleanupCursor::default(),
+            legacy_record_migration: None,
+            state_version: STATE_VERSION,
         }
     }
 }
@@ -206,8 +1179,17 @@ impl OracleIntentContract {
             intents: UnorderedMap::new(b"i"),
             evaluations: UnorderedMap::new(b"e"),
             challenges: UnorderedMap::new(b"c"),
+            quotes: UnorderedMap::new(StorageKey::Quotes),
+            pending_deadlines: TreeMap::new(StorageKey::PendingDeadlines),
             solvers: LookupMap::new(b"s"),
-            solver_stakes: LookupMap::new(b"s"),
+            solver_ids: Vector::new(StorageKey::SolverIds),
+            solver_stakes: LookupMap::new(StorageKey::SolverStakes),
+            solver_evaluations: LookupMap::new(StorageKey::SolverEvaluations),
+            commitments: LookupMap::new(StorageKey::Commitments),
+            failed_transfers: LookupMap::new(StorageKey::FailedTransfers),
+            active_evaluation_counts: LookupMap::new(StorageKey::ActiveEvaluationCounts),
+            max_active_evaluations_per_solver: DEFAULT_MAX_ACTIVE_EVALUATIONS_PER_SOLVER,
+            domain_reliability: LookupMap::new(StorageKey::DomainReliability),
             users: LookupMap::new(b"u"),
             admins: Vector::new(b"a"),
             verifiers: Vector::new(b"v"),
@@ -215,16 +1197,217 @@ impl OracleIntentContract {
             evaluation_counter: 0,
             challenge_counter: 0,
             min_stake: MIN_STAKE,
+            min_stake_by_type: LookupMap::new(StorageKey::MinStakeByType),
             max_evaluation_time: U64(300_000_000_000),
             challenge_period: U64(86_400_000_000_000),
+            appeal_period: U64(86_400_000_000_000),
+            reveal_delay: U64(60_000_000_000),
+            reputation_half_life: U64(DEFAULT_REPUTATION_HALF_LIFE),
+            slash_fraction_bps: DEFAULT_SLASH_FRACTION_BPS,
+            challenge_stake_multiplier: DEFAULT_CHALLENGE_STAKE_MULTIPLIER,
+            stake_token: None,
+            total_escrowed: 0,
+            bonus_pool: 0,
+            require_specialization_match: false,
+            min_challenge_period: U64(MIN_CHALLENGE_PERIOD),
+            max_challenge_period: U64(MAX_CHALLENGE_PERIOD),
+            paused: false,
+            pending_owner: None,
+            stale_scan_cursor: 0,
+            intent_status_counts: LookupMap::new(StorageKey::IntentStatusCounts),
+            evaluation_status_counts: LookupMap::new(StorageKey::EvaluationStatusCounts),
+            active_solver_count: 0,
+            reputation_sum: 0.0,
+            total_rewards_distributed: 0,
+            intent_templates: UnorderedMap::new(StorageKey::IntentTemplates),
+            pending_withdrawals: LookupMap::new(StorageKey::PendingWithdrawals),
+            amendment_window: U64(300_000_000_000),
+            verifier_stakes: LookupMap::new(StorageKey::VerifierStakes),
+            verifier_records: LookupMap::new(StorageKey::VerifierRecords),
+            dispute_votes: LookupMap::new(StorageKey::DisputeVotes),
+            solver_challenges: LookupMap::new(StorageKey::SolverChallenges),
+            registration_fee: 0,
+            protocol_treasury: 0,
+            tier_thresholds: TierThresholds::default(),
+            intent_history: LookupMap::new(StorageKey::IntentHistory),
+            min_evaluations_for_pool: DEFAULT_MIN_EVALUATIONS_FOR_POOL,
+            answer_tolerance: 0.0,
+            dispute_cooldown: U64(DEFAULT_DISPUTE_COOLDOWN),
+            max_reward: DEFAULT_MAX_REWARD,
+            area_performance: LookupMap::new(StorageKey::AreaPerformance),
+            protocol_fee_bps: 0,
+            reputation_archives: LookupMap::new(StorageKey::ReputationArchives),
+            reputation_reset_min_stake: DEFAULT_REPUTATION_RESET_MIN_STAKE,
+            reputation_reset_cooldown: U64(DEFAULT_REPUTATION_RESET_COOLDOWN),
+            challenge_withdrawal_penalty_bps: DEFAULT_CHALLENGE_WITHDRAWAL_PENALTY_BPS,
+            min_deadline_minutes: DEFAULT_MIN_DEADLINE_MINUTES,
+            max_deadline_minutes: DEFAULT_MAX_DEADLINE_MINUTES,
+            blacklist: LookupMap::new(StorageKey::Blacklist),
+            referrals: LookupMap::new(StorageKey::Referrals),
+            referral_stats: LookupMap::new(StorageKey::ReferralStats),
+            referral_bonus: 0,
+            stall_timeout: U64(DEFAULT_STALL_TIMEOUT),
+            allowed_domains: UnorderedMap::new(StorageKey::AllowedDomains),
+            enforce_domain_allowlist: false,
+            cleanup_cursor: CleanupCursor::default(),
+            reputation_prior_mean: DEFAULT_REPUTATION_PRIOR_MEAN,
+            reputation_prior_weight: DEFAULT_REPUTATION_PRIOR_WEIGHT,
+            require_source_diversity: false,
+            max_source_domain_share_bps: DEFAULT_MAX_SOURCE_DOMAIN_SHARE_BPS,
+            evaluation_challenges: LookupMap::new(StorageKey::EvaluationChallenges),
+            speed_bonus_cap_bps: DEFAULT_SPEED_BONUS_CAP_BPS,
+            speed_bonus_target_ms: DEFAULT_SPEED_BONUS_TARGET_MS,
+            force_expire_timeout: U64(DEFAULT_FORCE_EXPIRE_TIMEOUT),
+            solver_preferences: LookupMap::new(StorageKey::SolverPreferences),
+            dispute_winner_bps: BPS_DENOMINATOR as u16,
+            admin_actions: UnorderedMap::new(StorageKey::AdminActions),
+            next_admin_action_id: 0,
+            admin_action_delay: U64(DEFAULT_ADMIN_ACTION_DELAY),
+            keeper_reward: 0,
+            intent_reveal_delay: U64(0),
+            duplicate_links: LookupMap::new(StorageKey::DuplicateLinks),
+            linked_duplicates: LookupMap::new(StorageKey::LinkedDuplicates),
+            vesting_schedule: LookupMap::new(StorageKey::VestingSchedule),
+            vesting_threshold: 0,
+            vesting_period: U64(DEFAULT_VESTING_PERIOD),
+            vesting_tranches: DEFAULT_VESTING_TRANCHES,
+            min_challenger_reputation: 0.0,
+            min_challenger_verification_level: None,
+            dispute_settlement_deadline: U64(DEFAULT_DISPUTE_SETTLEMENT_DEADLINE),
+            solver_heartbeats: LookupMap::new(StorageKey::SolverHeartbeats),
+            source_corroboration_count: LookupMap::new(StorageKey::SourceCorroboration),
+            corroboration_bonus_bps: DEFAULT_CORROBORATION_BONUS_BPS,
+            corroboration_min_count: DEFAULT_CORROBORATION_MIN_COUNT,
+            max_evaluations_per_intent: MAX_QUORUM_EVALUATIONS as u32,
+            intent_evaluation_counts: LookupMap::new(StorageKey::IntentEvaluationCounts),
+            stake_locked_until: LookupMap::new(StorageKey::StakeLockedUntil),
+            open_appeal_counts: LookupMap::new(StorageKey::OpenAppealCounts),
+            auto_cleanup_cursor: CleanupCursor::default(),
+            legacy_record_migration: None,
+            state_version: STATE_VERSION,
+        }
+    }
+
+    /// Upgrade state written by the launch release to the current layout. Deploy the new code,
+    /// then call this once on the contract account.
+    ///
+    /// Settings added since launch take their defaults. Records keep their launch layout until
+    /// `migrate_legacy_records` rewrites them, and solvers until `migrate_legacy_solvers` does,
+    /// so the contract comes up paused and cannot be unpaused before the records are done.
+    #[private]
+    #[init(ignore_state)]
+    pub fn migrate() -> Self {
+        let state = env::storage_read(b"STATE").expect("Failed to read old contract state");
+        let old = OldOracleIntentContract::try_from_slice(&state)
+            .expect("State is not in the launch layout; it may already be migrated");
+
+        let mut contract = Self::new(old.owner);
+        contract.intents = old.intents;
+        contract.evaluations = old.evaluations;
+        contract.challenges = old.challenges;
+        contract.solvers = old.solvers;
+        contract.users = old.users;
+        contract.admins = old.admins;
+        contract.verifiers = old.verifiers;
+        contract.intent_counter = old.intent_counter;
+        contract.evaluation_counter = old.evaluation_counter;
+        contract.challenge_counter = old.challenge_counter;
+        contract.min_stake = old.min_stake;
+        contract.max_evaluation_time = old.max_evaluation_time;
+        contract.challenge_period = old.challenge_period;
+        contract.paused = true;
+        contract.legacy_record_migration = Some(LegacyRecordMigration::default());
+
+        env::log_str(&format!("Migrated contract state to version {}", STATE_VERSION));
+        contract
+    }
+
+    /// Rewrite up to `max_operations` records still in the launch layout: intents first, then
+    /// evaluations, then challenges. Each record also enters the indexes, status counts and
+    /// escrow total the launch release never kept. Returns true once every record is current.
+    ///
+    /// The launch release paid out disputes without closing them, so a challenge carried over
+    /// as Submitted may already be settled; check the "Dispute settled" logs before settling one.
+    pub fn migrate_legacy_records(&mut self, max_operations: u32) -> bool {
+        self.assert_owner();
+        let mut progress = self.legacy_record_migration
+            .expect("No legacy records left to migrate");
+        let mut remaining = max_operations as u64;
+
+        let intents: UnorderedMap<String, LegacyOracleIntent> = Self::legacy_view(&self.intents);
+        while remaining > 0 && progress.intents < intents.len() {
+            let legacy = intents.values_as_vector().get(progress.intents)
+                .expect("Legacy intent missing");
+            self.upgrade_legacy_intent(legacy);
+            progress.intents += 1;
+            remaining -= 1;
         }
+
+        let evaluations: UnorderedMap<String, LegacyOracleEvaluation> = Self::legacy_view(&self.evaluations);
+        while remaining > 0 && progress.evaluations < evaluations.len() {
+            let legacy = evaluations.values_as_vector().get(progress.evaluations)
+                .expect("Legacy evaluation missing");
+            self.upgrade_legacy_evaluation(legacy);
+            progress.evaluations += 1;
+            remaining -= 1;
+        }
+
+        let challenges: UnorderedMap<String, LegacyRefutationChallenge> = Self::legacy_view(&self.challenges);
+        while remaining > 0 && progress.challenges < challenges.len() {
+            let legacy = challenges.values_as_vector().get(progress.challenges)
+                .expect("Legacy challenge missing");
+            self.upgrade_legacy_challenge(legacy);
+            progress.challenges += 1;
+            remaining -= 1;
+        }
+
+        let done = progress.intents == intents.len()
+            && progress.evaluations == evaluations.len()
+            && progress.challenges == challenges.len();
+        if done {
+            self.legacy_record_migration = None;
+            env::log_str(&format!(
+                "LegacyRecordsMigrated: {} intents, {} evaluations, {} challenges",
+                progress.intents, progress.evaluations, progress.challenges
+            ));
+        } else {
+            self.legacy_record_migration = Some(progress);
+        }
+        done
+    }
+
+    /// Rebuild launch-layout solver records, which hold only the stake the solver registered
+    /// with. Solvers who submitted an evaluation are rebuilt by `migrate_legacy_records`; the
+    /// rest have to be listed here, since the launch release kept no solver index.
+    /// Returns how many of `solver_ids` were rebuilt.
+    pub fn migrate_legacy_solvers(&mut self, solver_ids: Vec<AccountId>) -> u32 {
+        self.assert_owner();
+        require!(solver_ids.len() as u64 <= MAX_PAGE_LIMIT, "Batch too large");
+
+        let mut migrated = 0;
+        for solver_id in solver_ids {
+            if self.upgrade_legacy_solver(&solver_id) {
+                migrated += 1;
+            }
+        }
+        migrated
+    }
+
+    /// Launch-layout migration progress; None once every record is current
+    pub fn get_legacy_record_migration(&self) -> Option<LegacyRecordMigration> {
+        self.legacy_record_migration
     }
 
-    /// Register a new user
-    pub fn register_user(&mut self, role: UserRole) {
+    /// Register a new user. Verifiers must attach at least `min_stake`.
+    #[payable]
+    pub fn register_user(&mut self, role: UserRole, referrer: Option<AccountId>) {
+        self.assert_not_paused();
         let account_id = env::predecessor_account_id();
         
         require!(!self.users.contains_key(&account_id), "User already registered");
+        if let Some(referrer) = referrer {
+            self.record_referral(&account_id, referrer);
+        }
         
         let user_profile = UserProfile {
             account_id: account_id.clone(),
@@ -246,7 +1429,11 @@ impl OracleIntentContract {
             },
             UserRole::Verifier => {
                 self.assert_admin_or_owner();
+                let stake = self.native_stake_deposit();
+                require!(stake >= self.min_stake, "Insufficient stake to register as verifier");
                 self.verifiers.push(&account_id);
+                self.verifier_stakes.insert(&account_id, &stake);
+                self.total_escrowed += stake;
             },
             _ => {}
         }
@@ -270,6 +1457,37 @@ impl OracleIntentContract {
         
         env::log_str(&format!("User {} verified at level {}", user_id, verification_level));
     }
+
+    /// Apply verification levels to many users at once (verifiers and admins). Unregistered
+    /// accounts are skipped and returned instead of failing the batch.
+    pub fn verify_users_batch(&mut self, users: Vec<(AccountId, u8)>) -> Vec<AccountId> {
+        self.assert_verifier_or_admin();
+        require!(users.len() as u64 <= MAX_PAGE_LIMIT, "Batch too large");
+        require!(
+            users.iter().all(|(_, verification_level)| *verification_level <= 5),
+            "Verification level must be 0-5"
+        );
+
+        let mut failed = Vec::new();
+        let mut verified_count = 0;
+        for (user_id, verification_level) in users {
+            let Some(mut user) = self.users.get(&user_id) else {
+                failed.push(user_id);
+                continue;
+            };
+            user.is_verified = verification_level > 0;
+            user.verification_level = verification_level;
+            self.users.insert(&user_id, &user);
+            verified_count += 1;
+        }
+
+        env::log_str(&format!(
+            "UsersVerified: {} updated, {} not registered",
+            verified_count,
+            failed.len()
+        ));
+        failed
+    }
     
     /// Update user role (only by admins)
     pub fn update_user_role(&mut self, user_id: AccountId, new_role: UserRole) {
@@ -299,6 +1517,10 @@ impl OracleIntentContract {
                     }
                 }
                 self.verifiers = new_verifiers;
+
+                if let Some(stake) = self.verifier_stakes.remove(&user_id) {
+                    self.credit_withdrawal(&user_id, stake);
+                }
             },
             _ => {}
         }
@@ -320,75 +1542,278 @@ impl OracleIntentContract {
         env::log_str(&format!("User {} role updated to {:?}", user_id, new_role));
     }
 
-    /// Register as an oracle solver
+    /// Register as an oracle solver. A solver who withdrew its whole stake may register again
+    /// and keeps its record; a staked solver tops up with `add_solver_stake` instead.
     #[payable]
-    pub fn register_solver(&mut self) {
+    pub fn register_solver(&mut self, referrer: Option<AccountId>) {
+        self.assert_not_paused();
         let solver_id = env::predecessor_account_id();
-        let stake_token = env::attached_deposit();
-        let stake = stake_token.as_yoctonear();
+        let stake = self.native_stake_deposit();
+        if let Some(referrer) = referrer {
+            self.record_referral(&solver_id, referrer);
+        }
+        self.internal_register_solver(solver_id, stake);
+    }
+
+    fn internal_register_solver(&mut self, solver_id: AccountId, deposit: Balance) {
+        ensure!(!self.blacklist.contains_key(&solver_id), OracleError::AccountBlacklisted);
+        ensure!(
+            self.solver_stakes.get(&solver_id).unwrap_or(0) == 0,
+            OracleError::SolverAlreadyRegistered
+        );
+        ensure!(deposit >= self.min_stake + self.registration_fee, OracleError::InsufficientRegistrationDeposit);
+        let stake = deposit - self.registration_fee;
+        self.protocol_treasury += self.registration_fee;
+        self.total_escrowed += self.registration_fee;
+
+        // A solver who withdrew everything comes back with its record intact, so re-registering
+        // cannot reset reputation, metrics or a cooldown
+        let solver = match self.solvers.get(&solver_id) {
+            Some(mut previous) => {
+                previous.total_stake = stake;
+                self.set_solver_active(&mut previous, true);
+                previous
+            },
+            None => {
+                let solver = self.new_solver_record(solver_id.clone(), stake);
+                self.solver_ids.push(&solver_id);
+                self.reputation_sum += solver.reputation_score;
+                self.active_solver_count += 1;
+                solver
+            },
+        };
+        self.solvers.insert(&solver_id, &solver);
+        self.solver_stakes.insert(&solver_id, &stake);
+        self.total_escrowed += stake;
         
-        require!(stake >= self.min_stake, "Insufficient stake to register as solver");
+        env::log_str(&format!("Solver {} registered with stake {}", solver_id, stake));
+    }
 
-        let solver = OracleSolver {
-            solver_id: solver_id.clone(),
-            reputation_score: 1.0,
-            total_evaluations: 0,
-            successful_evaluations: 0,
-            total_stake: stake,
-            is_active: true,
-            performance_metrics: SolverPerformanceMetrics {
-                average_execution_time: 0.0,
-                average_confidence_score: 0.0,
-                total_challenges_received: 0,
-                challenges_successfully_defended: 0,
-                total_rewards_earned: 0,
-                total_stakes_lost: 0,
-                last_active_timestamp: U64(env::block_timestamp()),
-                specialization_areas: vec![],
-                average_source_count: 0.0,
-                uptime_score: 1.0,
-            },
-        };
+    /// Top up a registered solver's stake with the attached deposit
+    #[payable]
+    pub fn add_solver_stake(&mut self) -> U128 {
+        self.assert_not_paused();
+        let solver_id = env::predecessor_account_id();
+        let amount = self.native_stake_deposit();
+        U128(self.internal_add_solver_stake(solver_id, amount))
+    }
+
+    fn internal_add_solver_stake(&mut self, solver_id: AccountId, amount: Balance) -> Balance {
+        require!(amount > 0, "Attach a non-zero deposit to add stake");
+
+        // Inactive solvers may top up too, so they can get back above min_stake and reactivate
+        let mut solver = self.solvers.get(&solver_id)
+            .expect("Solver not registered");
 
+        let new_total = self.solver_stakes.get(&solver_id).unwrap_or(0) + amount;
+        solver.total_stake = new_total;
         self.solvers.insert(&solver_id, &solver);
-        self.solver_stakes.insert(&solver_id, &stake);
-        
-        env::log_str(&format!("Solver {} registered with stake {}", solver_id, stake));
+        self.solver_stakes.insert(&solver_id, &new_total);
+        self.total_escrowed += amount;
+
+        env::log_str(&format!(
+            "Solver {} added stake {}, total stake {}",
+            solver_id, amount, new_total
+        ));
+
+        new_total
+    }
+
+    /// Withdraw part or all of a solver's stake. Withdrawing everything deregisters the solver.
+    /// Stake stays locked while the solver has evaluations open to challenge, or disputes that
+    /// are under appeal or can still be appealed.
+    pub fn withdraw_solver_stake(&mut self, amount: U128) {
+        self.assert_not_paused();
+        let solver_id = env::predecessor_account_id();
+        let amount = amount.0;
+
+        let mut solver = self.solvers.get(&solver_id)
+            .expect("Solver not registered");
+        let recorded_stake = self.solver_stakes.get(&solver_id).unwrap_or(0);
+
+        require!(amount > 0, "Withdrawal amount must be positive");
+        require!(amount <= recorded_stake, "Withdrawal amount exceeds recorded stake");
+
+        // Stake backing an evaluation that can still be slashed must stay locked
+        let open_evaluations = self.active_evaluation_counts.get(&solver_id).unwrap_or(0);
+        require!(open_evaluations == 0, "Cannot withdraw stake while evaluations are open to challenge");
+        // So must stake a settled dispute's reversal could still claw back
+        require!(
+            self.open_appeal_counts.get(&solver_id).unwrap_or(0) == 0 &&
+                env::block_timestamp() > self.stake_locked_until.get(&solver_id).map_or(0, |until| until.0),
+            "Cannot withdraw stake while a dispute can still be appealed"
+        );
+
+        let remaining_stake = recorded_stake - amount;
+        let deregister = remaining_stake == 0;
+        require!(
+            deregister || remaining_stake >= self.min_stake,
+            "Remaining stake would fall below minimum; withdraw the full stake to deregister"
+        );
+
+        solver.total_stake = remaining_stake;
+        if deregister {
+            self.set_solver_active(&mut solver, false);
+            self.solver_stakes.remove(&solver_id);
+        } else {
+            self.solver_stakes.insert(&solver_id, &remaining_stake);
+        }
+        self.solvers.insert(&solver_id, &solver);
+
+        self.transfer_reward(&solver_id, amount);
+
+        if deregister {
+            env::log_str(&format!("Solver {} withdrew full stake {} and deregistered", solver_id, amount));
+        } else {
+            env::log_str(&format!(
+                "Solver {} withdrew {} from stake, remaining stake {}",
+                solver_id, amount, remaining_stake
+            ));
+        }
     }
 
     /// Accept an intent for execution (solver claims intent)
     pub fn accept_intent(&mut self, intent_id: String) -> bool {
+        self.assert_not_paused();
         let solver = env::predecessor_account_id();
         
         // Verify solver is registered and has sufficient stake
         require!(self.solvers.contains_key(&solver), "Solver not registered");
         let solver_info = self.solvers.get(&solver).unwrap();
         require!(solver_info.is_active, "Solver is not active");
+        Self::assert_not_cooling_down(&solver_info);
         
         let mut intent = self.intents.get(&intent_id)
             .expect("Intent not found");
         
         require!(intent.status == IntentStatus::Pending, "Intent is not pending");
+        self.assert_intent_open(&intent);
         require!(env::block_timestamp() <= intent.deadline.0, "Intent has expired");
+        require!(intent.required_confirmations <= 1, "Quorum intents cannot be claimed by one solver");
+        Self::assert_not_excluded(&intent, &solver);
+        Self::assert_solver_allowed(&intent, &solver);
         
-        // Check if solver has sufficient reputation for high-value intents
-        if intent.reward > 5 * MIN_STAKE {
-            require!(solver_info.reputation_score >= 0.7, "Insufficient reputation for high-value intent");
-        }
+        self.assert_specialization_match(&solver_info, &intent);
+        self.assert_tier_for_intent(&solver_info, &intent);
         
-        intent.status = IntentStatus::InProgress;
+        self.set_intent_status(&mut intent, IntentStatus::InProgress);
+        intent.assigned_solver = Some(solver.clone());
+        intent.assigned_at = Some(U64(env::block_timestamp()));
         self.intents.insert(&intent_id, &intent);
+        self.record_intent_history(&intent_id, &solver, "accepted".to_string(), &intent.status);
         
         env::log_str(&format!("Intent {} accepted by solver {}", intent_id, solver));
         true
     }
     
+    /// Quote for a pending intent; the initiator later picks one quote with `select_quote`
+    pub fn submit_quote(
+        &mut self,
+        intent_id: String,
+        proposed_confidence: f64,
+        estimated_time_ms: U64,
+        bid_reward: U128,
+    ) {
+        self.assert_not_paused();
+        let bid_reward = bid_reward.0;
+        let solver = env::predecessor_account_id();
+
+        let solver_info = self.solvers.get(&solver)
+            .expect("Solver not registered");
+        require!(solver_info.is_active, "Solver is not active");
+        Self::assert_not_cooling_down(&solver_info);
+
+        let intent = self.intents.get(&intent_id)
+            .expect("Intent not found");
+
+        require!(intent.status == IntentStatus::Pending, "Intent is not pending");
+        self.assert_intent_open(&intent);
+        require!(env::block_timestamp() <= intent.deadline.0, "Intent has expired");
+        Self::assert_solver_allowed(&intent, &solver);
+        self.assert_specialization_match(&solver_info, &intent);
+        self.assert_tier_for_intent(&solver_info, &intent);
+        require!(
+            (0.0..=1.0).contains(&proposed_confidence),
+            "Proposed confidence must be between 0 and 1"
+        );
+        require!(bid_reward > 0, "Bid reward must be positive");
+        require!(bid_reward <= intent.reward, "Bid reward exceeds intent reward");
+
+        let quote = SolverQuote {
+            solver: solver.clone(),
+            proposed_confidence,
+            estimated_time_ms,
+            bid_reward,
+            submitted_at: U64(env::block_timestamp()),
+        };
+
+        // A solver holds at most one quote per intent; re-quoting replaces the previous bid
+        let mut intent_quotes = self.quotes.get(&intent_id).unwrap_or_default();
+        intent_quotes.retain(|existing| existing.solver != solver);
+        intent_quotes.push(quote);
+        self.quotes.insert(&intent_id, &intent_quotes);
+
+        env::log_str(&format!(
+            "Quote submitted by {} for intent {} with bid {}",
+            solver, intent_id, bid_reward
+        ));
+    }
+
+    /// Select the winning quote for an intent (initiator only), assigning the intent to that solver
+    pub fn select_quote(&mut self, intent_id: String, solver: AccountId) {
+        self.assert_not_paused();
+        let caller = env::predecessor_account_id();
+
+        let mut intent = self.intents.get(&intent_id)
+            .expect("Intent not found");
+
+        require!(intent.initiator == caller, "Only the initiator can select a quote");
+        require!(intent.status == IntentStatus::Pending, "Intent is not pending");
+        require!(env::block_timestamp() <= intent.deadline.0, "Intent has expired");
+        require!(intent.required_confirmations <= 1, "Quorum intents cannot be claimed by one solver");
+
+        let quote = self.quotes.get(&intent_id)
+            .unwrap_or_default()
+            .into_iter()
+            .find(|quote| quote.solver == solver)
+            .expect("No quote from this solver");
+
+        let solver_info = self.solvers.get(&solver)
+            .expect("Solver not registered");
+        require!(solver_info.is_active, "Solver is not active");
+        Self::assert_not_cooling_down(&solver_info);
+        Self::assert_not_excluded(&intent, &solver);
+        Self::assert_solver_allowed(&intent, &solver);
+        self.assert_specialization_match(&solver_info, &intent);
+        self.assert_tier_for_intent(&solver_info, &intent);
+
+        // The accepted bid becomes the reward; any excess stake goes back to the initiator
+        let refund = intent.reward - quote.bid_reward;
+        intent.reward = quote.bid_reward;
+        intent.stake -= refund;
+        self.set_intent_status(&mut intent, IntentStatus::InProgress);
+        intent.assigned_solver = Some(solver.clone());
+        intent.assigned_at = Some(U64(env::block_timestamp()));
+        self.intents.insert(&intent_id, &intent);
+        self.record_intent_history(&intent_id, &caller, format!("quote_selected:{}", solver), &intent.status);
+
+        if refund > 0 {
+            self.transfer_reward(&intent.initiator, refund);
+        }
+
+        env::log_str(&format!(
+            "Quote from {} selected for intent {} with reward {}",
+            solver, intent_id, intent.reward
+        ));
+    }
+
     /// Complete intent execution with result
     pub fn complete_intent_execution(
         &mut self,
         intent_id: String,
         evaluation_id: String,
     ) -> bool {
+        self.assert_not_paused();
         let solver = env::predecessor_account_id();
         
         let mut intent = self.intents.get(&intent_id)
@@ -400,9 +1825,12 @@ impl OracleIntentContract {
             .expect("Evaluation not found");
         
         require!(evaluation.solver == solver, "Only the assigned solver can complete this intent");
+        if let Some(assigned) = &intent.assigned_solver {
+            require!(*assigned == solver, "Only the assigned solver can complete this intent");
+        }
         require!(evaluation.intent_id == intent_id, "Evaluation does not match intent");
         
-        intent.status = IntentStatus::Completed;
+        self.set_intent_status(&mut intent, IntentStatus::Completed);
         intent.evaluation_hash = Some(evaluation_id);
         self.intents.insert(&intent_id, &intent);
         
@@ -419,37 +1847,135 @@ impl OracleIntentContract {
 
     /// Submit credibility evaluation intent
     #[payable]
-    pub fn submit_credibility_intent(
+    pub fn submit_credibility_intent(&mut self, input: CredibilityIntentInput) -> String {
+        self.assert_not_paused();
+        let initiator = env::predecessor_account_id();
+        let stake = self.native_stake_deposit();
+        self.internal_submit_credibility_intent(initiator, stake, input, None)
+    }
+
+    fn internal_submit_credibility_intent(
         &mut self,
-        question: String,
-        required_sources: Option<u32>,
-        confidence_threshold: Option<f64>,
-        deadline_minutes: Option<u64>,
+        initiator: AccountId,
+        deposit: Balance,
+        input: CredibilityIntentInput,
+        sealed: Option<SealedQuestion>,
     ) -> String {
-        let initiator = env::predecessor_account_id();
-        let stake_token = env::attached_deposit();
-        let stake = stake_token.as_yoctonear();
+        let CredibilityIntentInput {
+            question,
+            required_sources,
+            confidence_threshold,
+            deadline_minutes,
+            category,
+            priority,
+            challenge_period_minutes,
+            target_reward,
+            required_confirmations,
+            language,
+            allowed_solvers,
+        } = input;
+        // Without a target, a deposit above the reward cap is staked up to the cap
+        let stake = target_reward.map_or(deposit.min(self.max_reward), |target| target.0);
+        require!(stake <= deposit, "Target reward exceeds the attached deposit");
+        require!(stake >= self.min_stake, "Target reward is below the minimum stake");
+        require!(stake <= self.max_reward, "Target reward exceeds the maximum reward");
+        let excess = deposit - stake;
         let reward = stake;
+        let priority = priority.unwrap_or(Priority::Normal);
+        
+        require!(
+            stake >= self.effective_min_stake(&IntentType::CredibilityEvaluation) * priority.stake_multiplier(),
+            "Insufficient stake for intent priority"
+        );
         
-        require!(stake >= self.min_stake, "Insufficient stake for intent");
-        require!(!question.is_empty(), "Question cannot be empty");
-        require!(question.len() <= MAX_QUESTION_LENGTH, "Question too long");
+        match &sealed {
+            Some(sealed) => {
+                require!(question.is_empty(), "A private intent cannot also carry a plaintext question");
+                require!(
+                    !sealed.encrypted_question.is_empty() &&
+                        sealed.encrypted_question.len() <= MAX_ENCRYPTED_QUESTION_LENGTH,
+                    "Encrypted question must be 1-2048 characters"
+                );
+                require!(
+                    sealed.question_hash.len() == 64 &&
+                        sealed.question_hash.chars().all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c)),
+                    "Question hash must be a lowercase hex SHA-256 digest"
+                );
+            },
+            None => {
+                require!(!question.is_empty(), "Question cannot be empty");
+                require!(question.len() <= MAX_QUESTION_LENGTH, "Question too long");
+            },
+        }
         
         // Gas optimization: validate required_sources early
         let sources_required = required_sources.unwrap_or(3);
         require!(sources_required <= MAX_SOURCES_PER_EVALUATION as u32, "Too many sources required");
 
+        if let Some(threshold) = confidence_threshold {
+            require!(
+                threshold > 0.0 && threshold <= 1.0,
+                "Confidence threshold must be greater than 0 and at most 1"
+            );
+        }
+
+        if let Some(category) = &category {
+            require!(
+                !category.is_empty() && category.len() <= MAX_CATEGORY_LENGTH,
+                "Category must be 1-50 characters"
+            );
+        }
+
+        let required_confirmations = required_confirmations.unwrap_or(1);
+        require!(
+            (1..=MAX_REQUIRED_CONFIRMATIONS).contains(&required_confirmations),
+            "Required confirmations must be between 1 and 10"
+        );
+        require!(
+            required_confirmations <= self.max_evaluations_per_intent,
+            "Required confirmations exceed the per-intent evaluation limit"
+        );
+        let language = language.map(Self::normalize_language_tag);
+
+        let allowed_solvers = allowed_solvers.map(|mut solvers| {
+            solvers.sort();
+            solvers.dedup();
+            require!(
+                !solvers.is_empty() && solvers.len() <= MAX_ALLOWED_SOLVERS,
+                "Allowed solvers must list 1-50 accounts"
+            );
+            solvers
+        });
+
+        // Clamp a requested challenge period so it can't make evaluations effectively unchallengeable
+        let challenge_period = challenge_period_minutes.map(|minutes| {
+            U64(minutes
+                .saturating_mul(60 * 1_000_000_000)
+                .clamp(self.min_challenge_period.0, self.max_challenge_period.0))
+        });
+
         self.intent_counter += 1;
         let intent_id = format!("intent_{}", self.intent_counter);
         
+        // Without an explicit deadline, use the default pulled into the configured bounds
+        let deadline_minutes = deadline_minutes.unwrap_or(
+            DEFAULT_DEADLINE_MINUTES.clamp(self.min_deadline_minutes, self.max_deadline_minutes)
+        );
+        require!(
+            deadline_minutes >= self.min_deadline_minutes && deadline_minutes <= self.max_deadline_minutes,
+            format!(
+                "Deadline must be between {} and {} minutes",
+                self.min_deadline_minutes, self.max_deadline_minutes
+            )
+        );
         let deadline = env::block_timestamp() + 
-            (deadline_minutes.unwrap_or(60) * 60 * 1_000_000_000); // Convert minutes to nanoseconds
+            (deadline_minutes * 60 * 1_000_000_000); // Convert minutes to nanoseconds
 
         let intent = OracleIntent {
             intent_id: intent_id.clone(),
             intent_type: IntentType::CredibilityEvaluation,
             initiator,
-            question: Some(question.clone()),
+            question: sealed.is_none().then(|| question.clone()),
             evaluation_hash: None,
             challenge_hash: None,
             stake,
@@ -457,621 +1983,4976 @@ impl OracleIntentContract {
             deadline: U64(deadline),
             status: IntentStatus::Pending,
             created_at: U64(env::block_timestamp()),
+            assigned_solver: None,
+            category,
+            confidence_threshold,
+            required_sources: sources_required,
+            priority,
+            challenge_period,
+            required_confirmations,
+            quorum_evaluations: vec![],
+            excluded_solvers: vec![],
+            assigned_at: None,
+            encrypted_question: sealed.as_ref().map(|sealed| sealed.encrypted_question.clone()),
+            question_hash: sealed.map(|sealed| sealed.question_hash),
+            language,
+            allowed_solvers,
         };
 
         self.intents.insert(&intent_id, &intent);
+        self.adjust_intent_status_count(&IntentStatus::Pending, true);
+        self.pending_deadlines.insert(&(deadline, intent_id.clone()), &());
+        self.total_escrowed += stake;
+        self.record_intent_history(&intent_id, &intent.initiator, "submitted".to_string(), &intent.status);
+
+        // Return whatever the initiator attached beyond the target reward
+        if excess > 0 {
+            self.total_escrowed += excess;
+            self.transfer_reward(&intent.initiator, excess);
+            env::log_str(&format!("Refunded excess deposit of {} to {}", excess, intent.initiator));
+        }
         
-        env::log_str(&format!(
-            "Credibility intent {} submitted for question: {}", 
-            intent_id, question
-        ));
+        match &intent.question {
+            Some(question) => env::log_str(&format!(
+                "Credibility intent {} submitted for question: {}", 
+                intent_id, question
+            )),
+            None => env::log_str(&format!("Private credibility intent {} submitted", intent_id)),
+        }
 
         intent_id
     }
 
-    /// Submit evaluation result for an intent
+    /// Submit an intent whose question stays off-chain until it is answered. The initiator
+    /// stores the question encrypted, plus the hex SHA-256 of its plaintext; handing the key
+    /// or plaintext to solvers (for example to whoever accepts the intent) happens off-chain
+    /// and is outside the contract's guarantees. The answering solver passes the plaintext to
+    /// `submit_evaluation`, which checks it against the hash and publishes it on the intent.
     #[payable]
-    pub fn submit_evaluation(
-        &mut self,
-        intent_id: String,
-        answer: bool,
-        confidence: f64,
-        sources: Vec<Source>,
-        execution_time_ms: U64,
-    ) -> String {
-        let solver = env::predecessor_account_id();
-        let solver_stake_token = env::attached_deposit();
-        let solver_stake = solver_stake_token.as_yoctonear();
-        
-        require!(solver_stake >= self.min_stake, "Insufficient solver stake");
-        require!(confidence >= 0.0 && confidence <= 1.0, "Confidence must be between 0 and 1");
-        require!(!sources.is_empty(), "At least one source is required");
-        require!(sources.len() <= MAX_SOURCES_PER_EVALUATION, "Too many sources");
-        
-        // Gas optimization: validate sources early
-        for source in &sources {
-            require!(source.url.len() <= MAX_URL_LENGTH, "Source URL too long");
-            require!(!source.title.is_empty(), "Source title cannot be empty");
-        }
-        
+    pub fn submit_private_credibility_intent(&mut self, input: PrivateCredibilityIntentInput) -> String {
+        self.assert_not_paused();
+        let initiator = env::predecessor_account_id();
+        let stake = self.native_stake_deposit();
+        let PrivateCredibilityIntentInput {
+            encrypted_question,
+            question_hash,
+            required_sources,
+            confidence_threshold,
+            deadline_minutes,
+            category,
+            priority,
+            target_reward,
+        } = input;
+        self.internal_submit_credibility_intent(
+            initiator,
+            stake,
+            CredibilityIntentInput {
+                question: String::new(),
+                required_sources,
+                confidence_threshold,
+                deadline_minutes,
+                category,
+                priority,
+                challenge_period_minutes: None,
+                target_reward,
+                required_confirmations: None,
+                language: None,
+                allowed_solvers: None,
+            },
+            Some(SealedQuestion { encrypted_question, question_hash }),
+        )
+    }
+
+    /// Reopen an intent whose evaluation was refuted (initiator only), once the appeal period
+    /// has passed. The escrowed reward carries over, the deadline restarts with the original
+    /// duration, and the refuted solver may not answer it again.
+    pub fn relist_refuted_intent(&mut self, intent_id: String) {
+        self.assert_not_paused();
+        let caller = env::predecessor_account_id();
+
         let mut intent = self.intents.get(&intent_id)
             .expect("Intent not found");
-        
-        require!(intent.status == IntentStatus::Pending, "Intent is not pending");
-        require!(env::block_timestamp() <= intent.deadline.0, "Intent has expired");
-        
-        // Verify solver is registered
-        require!(self.solvers.contains_key(&solver), "Solver not registered");
+        require!(intent.initiator == caller, "Only the initiator can relist this intent");
+        require!(intent.status == IntentStatus::Settled, "Intent has not been settled");
+        require!(intent.required_confirmations <= 1, "Quorum intents cannot be relisted");
 
-        self.evaluation_counter += 1;
-        let evaluation_id = format!("eval_{}", self.evaluation_counter);
-        
-        let evaluation = OracleEvaluation {
-            evaluation_id: evaluation_id.clone(),
-            intent_id: intent_id.clone(),
-            solver: solver.clone(),
-            question: intent.question.clone().unwrap_or_default(),
-            answer,
-            confidence,
-            sources,
-            execution_time: execution_time_ms,
-            stake: solver_stake,
-            status: EvaluationStatus::Submitted,
-            submitted_at: U64(env::block_timestamp()),
-        };
+        let evaluation = intent.evaluation_hash.as_ref()
+            .and_then(|evaluation_id| self.evaluations.get(evaluation_id))
+            .expect("Evaluation not found");
+        require!(evaluation.status == EvaluationStatus::Refuted, "Only intents with a refuted evaluation can be relisted");
+        let challenge = intent.challenge_hash.as_ref()
+            .and_then(|challenge_id| self.challenges.get(challenge_id))
+            .expect("Challenge not found");
+        require!(challenge.status == ChallengeStatus::Successful, "Dispute is not finally refuted");
+        let settled_at = challenge.settled_at.expect("Dispute has not been settled");
+        require!(
+            env::block_timestamp() > settled_at.0 + self.appeal_period.0,
+            "Appeal period is still open"
+        );
 
-        self.evaluations.insert(&evaluation_id, &evaluation);
-        
-        // Update intent status
-        intent.status = IntentStatus::InProgress;
-        intent.evaluation_hash = Some(evaluation_id.clone());
+        let now = env::block_timestamp();
+        let duration = intent.deadline.0.saturating_sub(intent.created_at.0);
+        intent.excluded_solvers.push(evaluation.solver.clone());
+        intent.assigned_solver = None;
+        intent.assigned_at = None;
+        intent.evaluation_hash = None;
+        intent.challenge_hash = None;
+        intent.created_at = U64(now);
+        intent.deadline = U64(now + duration);
+        self.set_intent_status(&mut intent, IntentStatus::Pending);
         self.intents.insert(&intent_id, &intent);
+        self.pending_deadlines.insert(&(intent.deadline.0, intent_id.clone()), &());
+        self.quotes.remove(&intent_id);
+        self.record_intent_history(&intent_id, &caller, "relisted".to_string(), &intent.status);
 
         env::log_str(&format!(
-            "Evaluation {} submitted by {} for intent {}", 
-            evaluation_id, solver, intent_id
+            "IntentRelisted: {} with reward {} until {}, excluding {}",
+            intent_id, intent.reward, intent.deadline.0, evaluation.solver
         ));
-
-        evaluation_id
     }
 
-    /// Challenge an evaluation with counter-evidence
-    #[payable]
-    pub fn submit_challenge(
-        &mut self,
-        evaluation_id: String,
-        counter_sources: Vec<Source>,
-    ) -> String {
-        let challenger = env::predecessor_account_id();
-        let challenge_stake_token = env::attached_deposit();
-        let challenge_stake = challenge_stake_token.as_yoctonear();
-        
-        let evaluation = self.evaluations.get(&evaluation_id)
-            .expect("Evaluation not found");
-        
-        require!(
-            challenge_stake > evaluation.stake, 
-            "Challenge stake must be higher than evaluation stake"
-        );
-        require!(!counter_sources.is_empty(), "Counter sources required");
-        require!(
-            evaluation.status == EvaluationStatus::Submitted,
-            "Evaluation cannot be challenged"
-        );
-
-        // Check if challenge period is still open
-        let challenge_deadline = evaluation.submitted_at.0 + self.challenge_period.0;
-        require!(
-            env::block_timestamp() <= challenge_deadline,
-            "Challenge period has expired"
-        );
+    /// Put an accepted intent back up for grabs when its solver has not submitted an evaluation
+    /// within the stall timeout. The initiator may reclaim once the timeout passes; anyone may
+    /// after twice the timeout. The abandoning solver's uptime score is penalized.
+    pub fn reclaim_stalled_intent(&mut self, intent_id: String) {
+        self.assert_not_paused();
+        let caller = env::predecessor_account_id();
 
-        self.challenge_counter += 1;
-        let challenge_id = format!("challenge_{}", self.challenge_counter);
-        
-        let challenge = RefutationChallenge {
-            challenge_id: challenge_id.clone(),
-            evaluation_id: evaluation_id.clone(),
-            challenger: challenger.clone(),
-            counter_sources,
-            stake: challenge_stake,
-            status: ChallengeStatus::Submitted,
-            submitted_at: U64(env::block_timestamp()),
+        let mut intent = self.intents.get(&intent_id)
+            .expect("Intent not found");
+        require!(intent.status == IntentStatus::InProgress, "Intent is not in progress");
+        require!(intent.evaluation_hash.is_none(), "Assigned solver has already submitted an evaluation");
+        let now = env::block_timestamp();
+        require!(now <= intent.deadline.0, "Intent has expired");
+        let solver_id = intent.assigned_solver.clone()
+            .expect("Intent has no assigned solver");
+        let assigned_at = intent.assigned_at.map_or(intent.created_at.0, |at| at.0);
+        let timeout = if caller == intent.initiator {
+            self.stall_timeout.0
+        } else {
+            self.stall_timeout.0.saturating_mul(2)
         };
+        require!(now >= assigned_at.saturating_add(timeout), "Assigned solver is not stalled yet");
 
-        self.challenges.insert(&challenge_id, &challenge);
-        
-        // Update evaluation status
-        let mut updated_evaluation = evaluation;
-        updated_evaluation.status = EvaluationStatus::Challenged;
-        self.evaluations.insert(&evaluation_id, &updated_evaluation);
+        if let Some(mut solver) = self.solvers.get(&solver_id) {
+            solver.performance_metrics.uptime_score *= STALL_UPTIME_PENALTY;
+            self.solvers.insert(&solver_id, &solver);
+        }
+
+        intent.assigned_solver = None;
+        intent.assigned_at = None;
+        self.set_intent_status(&mut intent, IntentStatus::Pending);
+        self.intents.insert(&intent_id, &intent);
+        self.record_intent_history(
+            &intent_id,
+            &caller,
+            format!("reclaimed_from:{}", solver_id),
+            &intent.status,
+        );
 
         env::log_str(&format!(
-            "Challenge {} submitted by {} for evaluation {}", 
-            challenge_id, challenger, evaluation_id
+            "IntentReclaimed: {} from stalled solver {} by {}",
+            intent_id, solver_id, caller
         ));
-
-        challenge_id
     }
 
-    /// Settle a dispute between evaluation and challenge
-    pub fn settle_dispute(
-        &mut self,
-        evaluation_id: String,
-        challenge_id: String,
-        winner: String, // "evaluator", "challenger", or "tie"
-    ) {
-        self.assert_owner();
-        
-        let evaluation = self.evaluations.get(&evaluation_id)
-            .expect("Evaluation not found");
-        let challenge = self.challenges.get(&challenge_id)
-            .expect("Challenge not found");
-        
+    /// Cancel a pending intent that no solver has picked up and reclaim its stake
+    pub fn cancel_intent(&mut self, intent_id: String) {
+        self.assert_not_paused();
+        let caller = env::predecessor_account_id();
+
+        let mut intent = self.intents.get(&intent_id)
+            .expect("Intent not found");
+
+        require!(intent.initiator == caller, "Only the initiator can cancel this intent");
+        require!(intent.status == IntentStatus::Pending, "Only pending intents can be cancelled");
         require!(
-            evaluation.status == EvaluationStatus::Challenged,
-            "Evaluation is not in challenged state"
+            intent.quorum_evaluations.is_empty(),
+            "Intent has evaluations; finalize or settle them instead"
         );
 
-        let total_stake = evaluation.stake + challenge.stake;
-        
-        match winner.as_str() {
-            "evaluator" => {
-                // Evaluator wins, gets their stake back + challenge stake
-                self.transfer_reward(&evaluation.solver, total_stake);
-                self.update_solver_reputation(&evaluation.solver, true);
-                self.update_solver_challenge_metrics(&evaluation.solver, true);
-                self.update_solver_reputation(&challenge.challenger, false);
-                
-                // Update performance metrics for successful defense
-                self.update_solver_performance_metrics(
-                    &evaluation.solver,
-                    evaluation.execution_time.0 as f64,
-                    evaluation.confidence,
-                    evaluation.sources.len() as u64,
-                    total_stake
-                );
-            },
-            "challenger" => {
-                // Challenger wins, gets their stake back + evaluation stake  
-                self.transfer_reward(&challenge.challenger, total_stake);
-                self.update_solver_reputation(&challenge.challenger, true);
-                self.update_solver_challenge_metrics(&evaluation.solver, false);
-                self.update_solver_reputation(&evaluation.solver, false);
-                
-                // Track lost stakes for the evaluator
-                if let Some(mut solver) = self.solvers.get(&evaluation.solver) {
-                    solver.performance_metrics.total_stakes_lost += evaluation.stake;
-                    self.solvers.insert(&evaluation.solver, &solver);
-                }
-            },
-            "tie" => {
-                // Tie, everyone gets their stake back
-                self.transfer_reward(&evaluation.solver, evaluation.stake);
-                self.transfer_reward(&challenge.challenger, challenge.stake);
-            },
-            _ => env::panic_str("Invalid winner specification"),
-        }
+        self.set_intent_status(&mut intent, IntentStatus::Cancelled);
+        self.intents.insert(&intent_id, &intent);
+        self.forget_intent_links(&intent_id);
+        self.record_intent_history(&intent_id, &caller, "cancelled".to_string(), &intent.status);
 
-        env::log_str(&format!("Dispute settled: {} wins", winner));
+        self.transfer_reward(&intent.initiator, intent.stake);
+
+        env::log_str(&format!("Intent {} cancelled, stake {} returned to {}", intent_id, intent.stake, caller));
     }
-    
-    /// Distribute rewards based on performance and reputation
-    pub fn distribute_performance_rewards(&mut self, total_reward_pool: Balance) {
-        self.assert_owner();
-        
-        // Note: This would need to track active solver IDs separately in a production implementation
-        // For now, this is a placeholder since LookupMap doesn't support iteration
-        env::log_str(&format!("Would distribute {} reward pool", total_reward_pool));
+
+    /// Hand an open intent, and the right to its refunds, to another account (initiator only).
+    /// The user profile counters are only credited when an intent completes, so they follow
+    /// the intent to the new initiator without being moved here.
+    pub fn transfer_intent_ownership(&mut self, intent_id: String, new_initiator: AccountId) {
+        self.assert_not_paused();
+        let caller = env::predecessor_account_id();
+
+        let mut intent = self.intents.get(&intent_id)
+            .expect("Intent not found");
+        require!(intent.initiator == caller, "Only the initiator can transfer this intent");
+        require!(
+            matches!(intent.status, IntentStatus::Pending | IntentStatus::InProgress),
+            "Only pending or in-progress intents can be transferred"
+        );
+        require!(new_initiator != caller, "Intent already belongs to this account");
+        require!(!self.blacklist.contains_key(&new_initiator), "Account is blacklisted");
+
+        intent.initiator = new_initiator.clone();
+        self.intents.insert(&intent_id, &intent);
+        self.record_intent_history(&intent_id, &caller, format!("ownership_transferred:{}", new_initiator), &intent.status);
+
+        env::log_str(&format!("IntentOwnershipTransferred: {} from {} to {}", intent_id, caller, new_initiator));
     }
-    
-    /// Calculate automatic reward for successful evaluation (no challenges)
-    pub fn finalize_evaluation_reward(&mut self, evaluation_id: String) -> Balance {
-        let evaluation = self.evaluations.get(&evaluation_id)
-            .expect("Evaluation not found");
-            
+
+    /// Mark a pending intent as a duplicate of another intent asking the same question (owner/
+    /// admin only). The duplicate stops taking solvers; when the canonical intent's evaluation
+    /// is confirmed, the duplicate resolves with the same answer and its reward goes to that
+    /// evaluation's solver. A canonical intent cannot itself be a duplicate, so links never chain.
+    /// Duplicates are unlinked and reopen to solvers if the canonical intent is cancelled, expires
+    /// or goes through a dispute.
+    pub fn link_duplicate_intent(&mut self, intent_id: String, canonical_intent_id: String) {
+        self.assert_not_paused();
+        self.assert_admin_or_owner();
+        require!(intent_id != canonical_intent_id, "An intent cannot duplicate itself");
+
+        let intent = self.intents.get(&intent_id)
+            .expect("Intent not found");
+        let canonical = self.intents.get(&canonical_intent_id)
+            .expect("Canonical intent not found");
+        require!(intent.status == IntentStatus::Pending, "Only pending intents can be linked");
         require!(
-            evaluation.status == EvaluationStatus::Submitted,
-            "Evaluation already finalized"
+            intent.assigned_solver.is_none() && intent.quorum_evaluations.is_empty(),
+            "Intent already has solver work"
         );
-        
-        // Check if challenge period has expired
-        let challenge_deadline = evaluation.submitted_at.0 + self.challenge_period.0;
+        // A completed canonical still qualifies while its evaluation can be finalized
+        let canonical_open = match canonical.status {
+            IntentStatus::Pending | IntentStatus::InProgress => true,
+            IntentStatus::Completed => canonical.evaluation_hash.as_ref()
+                .and_then(|evaluation_id| self.evaluations.get(evaluation_id))
+                .is_some_and(|evaluation| matches!(evaluation.status, EvaluationStatus::Submitted | EvaluationStatus::Challenged)),
+            _ => false,
+        };
+        require!(canonical_open, "Canonical intent is already resolved");
         require!(
-            env::block_timestamp() > challenge_deadline,
-            "Challenge period still active"
+            intent.required_confirmations <= 1 && canonical.required_confirmations <= 1,
+            "Quorum intents cannot be linked"
         );
-        
+        require!(!self.duplicate_links.contains_key(&intent_id), "Intent is already linked");
+        require!(
+            !self.duplicate_links.contains_key(&canonical_intent_id),
+            "Canonical intent is itself a duplicate"
+        );
+        require!(
+            !self.linked_duplicates.contains_key(&intent_id),
+            "Intent is canonical for other duplicates"
+        );
+        require!(
+            Self::normalized_question(&intent).is_some() &&
+                Self::normalized_question(&intent) == Self::normalized_question(&canonical),
+            "Intents do not ask the same question"
+        );
+
+        let mut duplicates = self.linked_duplicates.get(&canonical_intent_id).unwrap_or_default();
+        duplicates.push(intent_id.clone());
+        self.linked_duplicates.insert(&canonical_intent_id, &duplicates);
+        self.duplicate_links.insert(&intent_id, &canonical_intent_id);
+        let caller = env::predecessor_account_id();
+        self.record_intent_history(&intent_id, &caller, format!("linked_to:{}", canonical_intent_id), &intent.status);
+
+        env::log_str(&format!("IntentLinked: {} -> {} by {}", intent_id, canonical_intent_id, caller));
+    }
+
+    /// Undo a duplicate link so the intent takes solvers again (owner/admin, or the
+    /// duplicate's initiator)
+    pub fn unlink_duplicate_intent(&mut self, intent_id: String) {
+        self.assert_not_paused();
+        let caller = env::predecessor_account_id();
+        let intent = self.intents.get(&intent_id)
+            .expect("Intent not found");
+        require!(
+            caller == intent.initiator || caller == self.owner || self.admins.iter().any(|admin| admin == caller),
+            "Only the initiator or an admin can unlink this intent"
+        );
+        let canonical_id = self.duplicate_links.get(&intent_id)
+            .expect("Intent is not linked");
+        self.forget_intent_links(&intent_id);
+
+        env::log_str(&format!("IntentUnlinked: {} from {} by {}", intent_id, canonical_id, caller));
+    }
+
+    /// Expire an intent that is wedged past its deadline and return the initiator's stake
+    /// (owner/admin only). Only intents without evaluations qualify: once an evaluation exists
+    /// the stake is paid out through finalization or dispute settlement instead.
+    pub fn force_expire_intent(&mut self, intent_id: String, reason: String) {
+        self.assert_admin_or_owner();
+        require!(!reason.is_empty(), "Reason cannot be empty");
+        let caller = env::predecessor_account_id();
+
+        let mut intent = self.intents.get(&intent_id)
+            .expect("Intent not found");
+        require!(
+            !matches!(intent.status, IntentStatus::Settled | IntentStatus::Expired | IntentStatus::Cancelled),
+            "Intent is already resolved"
+        );
+        require!(
+            intent.evaluation_hash.is_none() && intent.quorum_evaluations.is_empty(),
+            "Intent has evaluations; finalize or settle them instead"
+        );
+        require!(
+            env::block_timestamp() >= intent.deadline.0.saturating_add(self.force_expire_timeout.0),
+            "Intent has not been stuck past the force-expiry timeout"
+        );
+
+        intent.assigned_solver = None;
+        intent.assigned_at = None;
+        self.set_intent_status(&mut intent, IntentStatus::Expired);
+        self.intents.insert(&intent_id, &intent);
+        self.quotes.remove(&intent_id);
+        self.forget_intent_links(&intent_id);
+        self.record_intent_history(&intent_id, &caller, format!("force_expired:{}", reason), &intent.status);
+
+        self.transfer_reward(&intent.initiator, intent.stake);
+
+        env::log_str(&format!(
+            "IntentForceExpired: {} by {}, stake {} returned to {}: {}",
+            intent_id, caller, intent.stake, intent.initiator, reason
+        ));
+    }
+
+    /// Push back a pending intent's deadline, adding the attached deposit to its reward
+    #[payable]
+    pub fn extend_intent_deadline(&mut self, intent_id: String, additional_minutes: u64) {
+        self.assert_not_paused();
+        let caller = env::predecessor_account_id();
+        let additional_reward = self.native_stake_deposit();
+        self.internal_extend_intent_deadline(caller, additional_reward, intent_id, additional_minutes);
+    }
+
+    fn internal_extend_intent_deadline(
+        &mut self,
+        caller: AccountId,
+        additional_reward: Balance,
+        intent_id: String,
+        additional_minutes: u64,
+    ) {
+        let mut intent = self.intents.get(&intent_id)
+            .expect("Intent not found");
+
+        require!(intent.initiator == caller, "Only the initiator can extend this intent");
+        require!(intent.status == IntentStatus::Pending, "Intent is not pending");
+        require!(env::block_timestamp() <= intent.deadline.0, "Intent has expired");
+        require!(additional_minutes > 0, "Extension must be at least one minute");
+
+        let new_deadline = intent.deadline.0 + additional_minutes * 60 * 1_000_000_000;
+        let max_deadline = intent.created_at.0 + self.max_deadline_minutes * 60 * 1_000_000_000;
+        require!(
+            new_deadline <= max_deadline,
+            format!("Extension exceeds maximum intent duration of {} minutes", self.max_deadline_minutes)
+        );
+
+        intent.deadline = U64(new_deadline);
+        self.pending_deadlines.insert(&(new_deadline, intent_id.clone()), &());
+        require!(
+            intent.reward + additional_reward <= self.max_reward,
+            "Extended reward would exceed the maximum reward"
+        );
+        intent.reward += additional_reward;
+        intent.stake += additional_reward;
+        self.intents.insert(&intent_id, &intent);
+        self.total_escrowed += additional_reward;
+
+        env::log_str(&format!(
+            "Intent {} deadline extended to {} with reward {}",
+            intent_id, new_deadline, intent.reward
+        ));
+    }
+
+    /// Submit evaluation result for an intent
+    #[payable]
+    pub fn submit_evaluation(&mut self, input: EvaluationInput) -> String {
+        self.assert_not_paused();
+        let solver = env::predecessor_account_id();
+        let solver_stake = self.native_stake_deposit();
+        self.internal_submit_evaluation(solver, solver_stake, input)
+    }
+
+    /// Submit an evaluation whose answer is a number or a category rather than yes/no
+    #[payable]
+    pub fn submit_numeric_evaluation(
+        &mut self,
+        intent_id: String,
+        answer: AnswerValue,
+        confidence: f64,
+        sources: Vec<Source>,
+        execution_time_ms: U64,
+    ) -> String {
+        self.assert_not_paused();
+        let solver = env::predecessor_account_id();
+        let solver_stake = self.native_stake_deposit();
+        self.internal_submit_evaluation(
+            solver,
+            solver_stake,
+            EvaluationInput {
+                intent_id,
+                answer: false,
+                confidence,
+                sources,
+                execution_time_ms,
+                price_threshold: None,
+                answer_value: Some(answer),
+                question: None,
+            },
+        )
+    }
+
+    /// Correct a submitted evaluation shortly after submitting it (evaluation's solver only).
+    /// A changed answer or source list restarts the challenge period. `answer_value`, when
+    /// given, replaces `answer` for numeric and categorical evaluations.
+    pub fn amend_evaluation(
+        &mut self,
+        evaluation_id: String,
+        answer: bool,
+        confidence: f64,
+        sources: Vec<Source>,
+        answer_value: Option<AnswerValue>,
+    ) {
+        self.assert_not_paused();
+        let mut evaluation = self.evaluations.get(&evaluation_id)
+            .expect("Evaluation not found");
+
+        require!(env::predecessor_account_id() == evaluation.solver, "Only the evaluation's solver can amend it");
+        require!(evaluation.status == EvaluationStatus::Submitted, "Evaluation can no longer be amended");
+        require!(
+            env::block_timestamp() <= evaluation.submitted_at.0 + self.amendment_window.0,
+            "Amendment window has closed"
+        );
+        require!((0.0..=1.0).contains(&confidence), "Confidence must be between 0 and 1");
+        Self::assert_valid_sources(&sources);
+        self.assert_allowed_domains(&sources);
+        self.assert_source_diversity(&sources);
         let intent = self.intents.get(&evaluation.intent_id)
             .expect("Intent not found");
-            
-        // Calculate base reward
-        let mut total_reward = intent.reward + evaluation.stake;
+        // A quorum evaluation could otherwise be changed to match the others once they are visible
+        require!(intent.required_confirmations <= 1, "Quorum evaluations cannot be amended");
+        require!(
+            sources.len() >= intent.required_sources as usize,
+            "Not enough sources for this intent"
+        );
+
+        let (answer, answer_value) = Self::normalize_answer(answer, answer_value);
+        let amended_value = answer_value.clone().unwrap_or(AnswerValue::Boolean(answer));
+        let substantive = !amended_value.agrees_with(&evaluation.answer_value(), self.answer_tolerance)
+            || sources != evaluation.sources;
+        evaluation.answer = answer;
+        evaluation.answer_value = answer_value;
+        evaluation.confidence = confidence;
+        evaluation.sources = sources;
+        if substantive {
+            evaluation.amended_at = Some(U64(env::block_timestamp()));
+        }
+        self.evaluations.insert(&evaluation_id, &evaluation);
+
+        env::log_str(&format!(
+            "EvaluationAmended: {} by {}{}",
+            evaluation_id,
+            evaluation.solver,
+            if substantive { ", challenge period restarted" } else { "" }
+        ));
+    }
+
+    /// Submit several evaluations in one call. The attached stake is split evenly across
+    /// the submissions (any remainder goes to the last one); if any item fails validation
+    /// the whole batch reverts.
+    #[payable]
+    pub fn submit_evaluations_batch(&mut self, submissions: Vec<EvaluationInput>) -> Vec<String> {
+        self.assert_not_paused();
+        let solver = env::predecessor_account_id();
+        let total_stake = self.native_stake_deposit();
+        self.internal_submit_evaluations_batch(solver, total_stake, submissions)
+    }
+
+    fn internal_submit_evaluations_batch(
+        &mut self,
+        solver: AccountId,
+        total_stake: Balance,
+        submissions: Vec<EvaluationInput>,
+    ) -> Vec<String> {
+        require!(!submissions.is_empty(), "Batch cannot be empty");
+        require!(submissions.len() as u64 <= MAX_PAGE_LIMIT, "Batch too large");
+
+        let count = submissions.len() as u128;
+        let stake_per_item = total_stake / count;
+        let remainder = total_stake % count;
+        let last_index = submissions.len() - 1;
+
+        submissions
+            .into_iter()
+            .enumerate()
+            .map(|(index, input)| {
+                let stake = if index == last_index { stake_per_item + remainder } else { stake_per_item };
+                self.internal_submit_evaluation(solver.clone(), stake, input)
+            })
+            .collect()
+    }
+
+    fn internal_submit_evaluation(
+        &mut self,
+        solver: AccountId,
+        solver_stake: Balance,
+        input: EvaluationInput,
+    ) -> String {
+        let EvaluationInput { intent_id, answer, confidence, sources, execution_time_ms, price_threshold, answer_value, question } = input;
+        let (answer, answer_value) = Self::normalize_answer(answer, answer_value);
+
+        ensure!(solver_stake >= self.min_stake, OracleError::InsufficientSolverStake);
+        ensure!((0.0..=1.0).contains(&confidence), OracleError::InvalidConfidence);
+        if let Some(threshold) = price_threshold {
+            ensure!(threshold.is_finite() && threshold >= 0.0, OracleError::InvalidPriceThreshold);
+        }
+        // Gas optimization: validate sources early
+        Self::assert_valid_sources(&sources);
+        self.assert_allowed_domains(&sources);
+        self.assert_source_diversity(&sources);
         
-        // Apply reputation multiplier
-        if let Some(solver) = self.solvers.get(&evaluation.solver) {
-            let reputation_multiplier = 1.0 + (solver.reputation_score - 0.5) * 0.5; // 0.75x to 1.25x
-            total_reward = (total_reward as f64 * reputation_multiplier) as Balance;
-            
-            // Apply performance bonus for fast execution
-            let execution_time_seconds = evaluation.execution_time.0 as f64 / 1000.0;
-            if execution_time_seconds < 60.0 { // Under 1 minute
-                let speed_bonus = (60.0 - execution_time_seconds) / 60.0 * 0.1; // Up to 10% bonus
-                total_reward = (total_reward as f64 * (1.0 + speed_bonus)) as Balance;
-            }
+        let mut intent = self.intents.get(&intent_id)
+            .unwrap_or_else(|| OracleError::IntentNotFound.panic());
+        
+        // A claimed intent (via accept_intent or select_quote) is InProgress and only accepts
+        // evaluations from its assigned solver; unclaimed intents can be answered directly while Pending
+        match &intent.assigned_solver {
+            Some(assigned) => {
+                ensure!(*assigned == solver, OracleError::IntentAssignedToAnotherSolver);
+                ensure!(intent.status == IntentStatus::InProgress, OracleError::IntentNotInProgress);
+            },
+            None => {
+                ensure!(intent.status == IntentStatus::Pending, OracleError::IntentNotPending);
+                self.assert_intent_open(&intent);
+            },
+        }
+        // Answering a private intent reveals its question, checked against the stored hash
+        if let (None, Some(question_hash)) = (&intent.question, &intent.question_hash) {
+            let question = question.expect("The plaintext question is required for a private intent");
+            require!(
+                Self::hex_sha256(&question) == *question_hash,
+                "Question does not match the private intent's hash"
+            );
+            intent.question = Some(question);
+        }
+        ensure!(env::block_timestamp() <= intent.deadline.0, OracleError::IntentExpired);
+        let intent_evaluations = self.intent_evaluation_counts.get(&intent_id).unwrap_or(0);
+        ensure!(
+            intent_evaluations < self.max_evaluations_per_intent,
+            OracleError::TooManyEvaluationsForIntent,
+            "limit {}",
+            self.max_evaluations_per_intent
+        );
+        if intent.required_confirmations > 1 {
+            ensure!(intent.quorum_evaluations.len() < MAX_QUORUM_EVALUATIONS, OracleError::QuorumFull);
+            ensure!(
+                !intent.quorum_evaluations.iter().any(|id| {
+                    self.evaluations.get(id).is_some_and(|existing| existing.solver == solver)
+                }),
+                OracleError::AlreadyEvaluated
+            );
         }
         
-        // Transfer reward
-        self.transfer_reward(&evaluation.solver, total_reward);
+        ensure!(
+            sources.len() >= intent.required_sources as usize,
+            OracleError::NotEnoughSources,
+            "{} required",
+            intent.required_sources
+        );
         
-        // Update evaluation status
-        let mut updated_evaluation = evaluation;
-        updated_evaluation.status = EvaluationStatus::Confirmed;
-        self.evaluations.insert(&evaluation_id, &updated_evaluation);
+        // Verify solver is registered
+        let solver_info = self.solvers.get(&solver)
+            .unwrap_or_else(|| OracleError::SolverNotRegistered.panic());
+        ensure!(solver_info.is_active, OracleError::SolverNotActive);
+        Self::assert_not_cooling_down(&solver_info);
+        Self::assert_not_excluded(&intent, &solver);
+        Self::assert_solver_allowed(&intent, &solver);
+        self.assert_specialization_match(&solver_info, &intent);
+
+        let active_evaluations = self.active_evaluation_counts.get(&solver).unwrap_or(0);
+        ensure!(
+            active_evaluations < self.max_active_evaluations_per_solver,
+            OracleError::TooManyActiveEvaluations
+        );
+        self.active_evaluation_counts.insert(&solver, &(active_evaluations + 1));
+        self.intent_evaluation_counts.insert(&intent_id, &(intent_evaluations + 1));
+
+        self.evaluation_counter += 1;
+        let evaluation_id = format!("eval_{}", self.evaluation_counter);
         
-        // Update solver performance metrics
-        self.update_solver_performance_metrics(
-            &updated_evaluation.solver,
-            updated_evaluation.execution_time.0 as f64,
-            updated_evaluation.confidence,
-            updated_evaluation.sources.len() as u64,
-            total_reward
+        let evaluation = OracleEvaluation {
+            evaluation_id: evaluation_id.clone(),
+            intent_id: intent_id.clone(),
+            solver: solver.clone(),
+            question: intent.question.clone().unwrap_or_default(),
+            answer,
+            confidence,
+            sources,
+            execution_time: execution_time_ms,
+            stake: solver_stake,
+            status: EvaluationStatus::Submitted,
+            submitted_at: U64(env::block_timestamp()),
+            reputation_counted: false,
+            contributors: vec![],
+            amended_at: None,
+            price_threshold,
+            price_feed_result: None,
+            answer_value,
+        };
+
+        self.evaluations.insert(&evaluation_id, &evaluation);
+        self.adjust_evaluation_status_count(&EvaluationStatus::Submitted, true);
+        self.total_escrowed += solver_stake;
+
+        Self::push_indexed_id(&mut self.solver_evaluations, &solver, &evaluation_id, |account_hash| {
+            StorageKey::SolverEvaluationIds { account_hash }
+        });
+        
+        // Update intent status; a quorum intent stays open until enough evaluations agree
+        if intent.required_confirmations > 1 {
+            intent.quorum_evaluations.push(evaluation_id.clone());
+            let (confirming, _) = self.confirmation_status(&intent);
+            if confirming.len() as u32 >= intent.required_confirmations {
+                self.set_intent_status(&mut intent, IntentStatus::Completed);
+                intent.evaluation_hash = confirming.first().cloned();
+                env::log_str(&format!(
+                    "QuorumReached: intent {} confirmed by {} evaluations",
+                    intent_id,
+                    confirming.len()
+                ));
+            }
+        } else {
+            self.set_intent_status(&mut intent, IntentStatus::InProgress);
+            if intent.assigned_solver.is_none() {
+                intent.assigned_solver = Some(solver.clone());
+                intent.assigned_at = Some(U64(env::block_timestamp()));
+            }
+            intent.evaluation_hash = Some(evaluation_id.clone());
+        }
+        self.intents.insert(&intent_id, &intent);
+        self.record_intent_history(
+            &intent_id,
+            &solver,
+            format!("evaluation_submitted:{}", evaluation_id),
+            &intent.status,
+        );
+
+        env::log_str(&format!(
+            "Evaluation {} submitted by {} for intent {}", 
+            evaluation_id, solver, intent_id
+        ));
+
+        evaluation_id
+    }
+
+    /// Submit an evaluation researched by several registered solvers. `contributors` pairs each
+    /// solver with their share of the reward; the weights must sum to 1.0 and include the caller.
+    #[payable]
+    pub fn submit_collaborative_evaluation(
+        &mut self,
+        intent_id: String,
+        contributors: Vec<(AccountId, f64)>,
+        answer: bool,
+        confidence: f64,
+        sources: Vec<Source>,
+        execution_time_ms: U64,
+    ) -> String {
+        self.assert_not_paused();
+        let solver = env::predecessor_account_id();
+        let solver_stake = self.native_stake_deposit();
+
+        require!(!contributors.is_empty(), "At least one contributor required");
+        require!(contributors.len() <= MAX_CONTRIBUTORS, "Too many contributors");
+        require!(
+            contributors.iter().any(|(contributor, _)| *contributor == solver),
+            "Caller must be one of the contributors"
+        );
+        for (index, (contributor, weight)) in contributors.iter().enumerate() {
+            require!(weight.is_finite() && *weight > 0.0, "Contribution weights must be positive");
+            require!(self.solvers.contains_key(contributor), "Contributor is not a registered solver");
+            require!(
+                !contributors[..index].iter().any(|(other, _)| other == contributor),
+                "Duplicate contributor"
+            );
+        }
+        let total_weight: f64 = contributors.iter().map(|(_, weight)| weight).sum();
+        require!(
+            (total_weight - 1.0).abs() <= CONTRIBUTION_WEIGHT_TOLERANCE,
+            "Contribution weights must sum to 1.0"
+        );
+
+        let evaluation_id = self.internal_submit_evaluation(
+            solver,
+            solver_stake,
+            EvaluationInput {
+                intent_id,
+                answer,
+                confidence,
+                sources,
+                execution_time_ms,
+                price_threshold: None,
+                answer_value: None,
+                question: None,
+            },
+        );
+
+        let mut evaluation = self.evaluations.get(&evaluation_id)
+            .expect("Evaluation not found");
+        evaluation.contributors = contributors;
+        self.evaluations.insert(&evaluation_id, &evaluation);
+
+        evaluation_id
+    }
+
+    /// Save a reusable intent template under `name`, replacing any template of the same name
+    pub fn create_intent_template(
+        &mut self,
+        name: String,
+        question_pattern: String,
+        required_sources: u32,
+        confidence_threshold: f64,
+    ) {
+        self.assert_not_paused();
+        let owner = env::predecessor_account_id();
+
+        require!(
+            !name.is_empty() && name.len() <= MAX_TEMPLATE_NAME_LENGTH,
+            "Template name must be 1-50 characters"
+        );
+        require!(!question_pattern.is_empty(), "Question pattern cannot be empty");
+        require!(question_pattern.len() <= MAX_QUESTION_LENGTH, "Question pattern too long");
+        require!(required_sources <= MAX_SOURCES_PER_EVALUATION as u32, "Too many sources required");
+        require!(
+            confidence_threshold > 0.0 && confidence_threshold <= 1.0,
+            "Confidence threshold must be greater than 0 and at most 1"
+        );
+
+        let template = IntentTemplate {
+            owner: owner.clone(),
+            name: name.clone(),
+            question_pattern,
+            required_sources,
+            confidence_threshold,
+            created_at: U64(env::block_timestamp()),
+        };
+        self.intent_templates.insert(&Self::template_key(&owner, &name), &template);
+
+        env::log_str(&format!("Intent template {} saved by {}", name, owner));
+    }
+
+    /// Delete one of the caller's templates
+    pub fn delete_intent_template(&mut self, name: String) {
+        self.assert_not_paused();
+        let owner = env::predecessor_account_id();
+        self.intent_templates.remove(&Self::template_key(&owner, &name))
+            .expect("Template not found");
+
+        env::log_str(&format!("Intent template {} deleted by {}", name, owner));
+    }
+
+    /// Submit a credibility intent from one of the caller's templates, replacing each
+    /// `{key}` in the pattern with its value from `substitutions`
+    #[payable]
+    pub fn submit_intent_from_template(
+        &mut self,
+        template_name: String,
+        substitutions: Vec<(String, String)>,
+    ) -> String {
+        self.assert_not_paused();
+        let initiator = env::predecessor_account_id();
+        let stake = self.native_stake_deposit();
+
+        let template = self.intent_templates.get(&Self::template_key(&initiator, &template_name))
+            .expect("Template not found");
+
+        let mut question = template.question_pattern.clone();
+        for (key, value) in &substitutions {
+            question = question.replace(&format!("{{{}}}", key), value);
+        }
+        require!(
+            !(question.contains('{') && question.contains('}')),
+            "Template has unfilled placeholders"
+        );
+
+        self.internal_submit_credibility_intent(
+            initiator,
+            stake,
+            CredibilityIntentInput {
+                question,
+                required_sources: Some(template.required_sources),
+                confidence_threshold: Some(template.confidence_threshold),
+                deadline_minutes: None,
+                category: None,
+                priority: None,
+                challenge_period_minutes: None,
+                target_reward: None,
+                required_confirmations: None,
+                language: None,
+                allowed_solvers: None,
+            },
+            None,
+        )
+    }
+
+    fn template_key(owner: &AccountId, name: &str) -> String {
+        format!("{}:{}", owner, name)
+    }
+
+    /// Seal an answer for an intent without revealing it. `commitment` is the hex sha256 of
+    /// `"{answer}|{confidence}|{sources as JSON}|{nonce}"`, checked by `reveal_evaluation`.
+    pub fn commit_evaluation(&mut self, intent_id: String, commitment: String) {
+        self.assert_not_paused();
+        let solver = env::predecessor_account_id();
+        require!(self.solvers.contains_key(&solver), "Solver not registered");
+        require!(commitment.len() == 64, "Commitment must be a hex sha256 digest");
+
+        let intent = self.intents.get(&intent_id)
+            .expect("Intent not found");
+        require!(intent.status == IntentStatus::Pending, "Intent is not pending");
+        require!(env::block_timestamp() <= intent.deadline.0, "Intent has expired");
+
+        self.commitments.insert(
+            &(intent_id.clone(), solver.clone()),
+            &EvaluationCommitment {
+                commitment: commitment.to_lowercase(),
+                committed_at: U64(env::block_timestamp()),
+            },
+        );
+
+        env::log_str(&format!("Solver {} committed an evaluation for intent {}", solver, intent_id));
+    }
+
+    /// Reveal a committed evaluation once `reveal_delay` has passed; the attached deposit is the
+    /// evaluation stake. Execution time is measured from intent creation to the commitment.
+    #[payable]
+    pub fn reveal_evaluation(
+        &mut self,
+        intent_id: String,
+        answer: bool,
+        confidence: f64,
+        sources: Vec<Source>,
+        nonce: String,
+    ) -> String {
+        self.assert_not_paused();
+        let solver = env::predecessor_account_id();
+        let solver_stake = self.native_stake_deposit();
+
+        let key = (intent_id.clone(), solver.clone());
+        let sealed = self.commitments.get(&key)
+            .expect("No commitment for this intent");
+        require!(
+            env::block_timestamp() >= sealed.committed_at.0 + self.reveal_delay.0,
+            "Reveal window has not opened yet"
+        );
+
+        let preimage = format!(
+            "{}|{}|{}|{}",
+            answer,
+            confidence,
+            near_sdk::serde_json::to_string(&sources).unwrap(),
+            nonce
+        );
+        let digest = Self::hex_sha256(&preimage);
+        require!(digest == sealed.commitment, "Reveal does not match commitment");
+        self.commitments.remove(&key);
+
+        let intent = self.intents.get(&intent_id)
+            .expect("Intent not found");
+        let execution_time_ms = sealed.committed_at.0.saturating_sub(intent.created_at.0) / 1_000_000;
+
+        self.internal_submit_evaluation(
+            solver,
+            solver_stake,
+            EvaluationInput {
+                intent_id,
+                answer,
+                confidence,
+                sources,
+                execution_time_ms: U64(execution_time_ms),
+                price_threshold: None,
+                answer_value: None,
+                question: None,
+            },
+        )
+    }
+
+    /// NEP-141 receiver: accepts stake sent with `ft_transfer_call` when a stake token is configured.
+    /// The `msg` is a JSON-encoded `StakeTransferMessage` naming the action to perform.
+    pub fn ft_on_transfer(&mut self, sender_id: AccountId, amount: U128, msg: String) -> PromiseOrValue<U128> {
+        self.assert_not_paused();
+        let stake_token = self.stake_token.clone()
+            .expect("Stake token is not configured");
+        require!(env::predecessor_account_id() == stake_token, "Only the stake token can transfer stake");
+
+        let message: StakeTransferMessage = near_sdk::serde_json::from_str(&msg)
+            .expect("Invalid stake transfer message");
+
+        match message {
+            StakeTransferMessage::RegisterSolver => {
+                self.internal_register_solver(sender_id, amount.0);
+            },
+            StakeTransferMessage::AddSolverStake => {
+                self.internal_add_solver_stake(sender_id, amount.0);
+            },
+            StakeTransferMessage::SubmitCredibilityIntent(input) => {
+                self.internal_submit_credibility_intent(sender_id, amount.0, input, None);
+            },
+            StakeTransferMessage::SubmitEvaluation(input) => {
+                self.internal_submit_evaluation(sender_id, amount.0, input);
+            },
+            StakeTransferMessage::SubmitEvaluationsBatch { submissions } => {
+                self.internal_submit_evaluations_batch(sender_id, amount.0, submissions);
+            },
+            StakeTransferMessage::ExtendIntentDeadline { intent_id, additional_minutes } => {
+                self.internal_extend_intent_deadline(sender_id, amount.0, intent_id, additional_minutes);
+            },
+            StakeTransferMessage::FundBonusPool => {
+                require!(sender_id == self.owner, "Only owner can fund the bonus pool");
+                self.internal_fund_bonus_pool(amount.0);
+            },
+            StakeTransferMessage::SubmitChallenge { evaluation_id, counter_sources } => {
+                self.internal_submit_challenge(sender_id, amount.0, evaluation_id, counter_sources);
+            },
+        }
+
+        // The full amount is kept as stake
+        PromiseOrValue::Value(U128(0))
+    }
+
+    /// Challenge an evaluation with counter-evidence
+    #[payable]
+    pub fn submit_challenge(
+        &mut self,
+        evaluation_id: String,
+        counter_sources: Vec<Source>,
+    ) -> String {
+        self.assert_not_paused();
+        let challenger = env::predecessor_account_id();
+        let challenge_stake = self.native_stake_deposit();
+        self.internal_submit_challenge(challenger, challenge_stake, evaluation_id, counter_sources)
+    }
+
+    fn internal_submit_challenge(
+        &mut self,
+        challenger: AccountId,
+        challenge_stake: Balance,
+        evaluation_id: String,
+        counter_sources: Vec<Source>,
+    ) -> String {
+        // Solvers put their reputation behind a challenge; other accounts need a verified identity
+        match self.solvers.get(&challenger) {
+            Some(challenger_info) => {
+                ensure!(
+                    self.tier_thresholds.tier_for(&challenger_info) >= ReputationTier::Silver,
+                    OracleError::ChallengerTierTooLow
+                );
+                ensure!(
+                    challenger_info.reputation_score >= self.min_challenger_reputation,
+                    OracleError::ChallengerReputationTooLow,
+                    "{} required, {} held",
+                    self.min_challenger_reputation,
+                    challenger_info.reputation_score
+                );
+            },
+            None => ensure!(
+                self.min_challenger_verification_level
+                    .is_some_and(|min_level| self.is_user_verified(&challenger, min_level)),
+                OracleError::ChallengerNotRegistered
+            ),
+        }
+
+        let evaluation = self.evaluations.get(&evaluation_id)
+            .unwrap_or_else(|| OracleError::EvaluationNotFound.panic());
+        
+        // Work in basis points so the integer stake isn't rounded through f64
+        let multiplier_bps = (self.challenge_stake_multiplier * BPS_DENOMINATOR as f64).round() as u128;
+        ensure!(
+            challenge_stake >= evaluation.stake * multiplier_bps / BPS_DENOMINATOR,
+            OracleError::ChallengeStakeBelowMultiple,
+            "at least {}x",
+            self.challenge_stake_multiplier
+        );
+        ensure!(
+            challenge_stake >= self.effective_min_stake(&IntentType::RefutationChallenge),
+            OracleError::InsufficientChallengeStake
+        );
+        ensure!(!counter_sources.is_empty(), OracleError::CounterSourcesRequired);
+        Self::assert_valid_sources(&counter_sources);
+        self.assert_allowed_domains(&counter_sources);
+        ensure!(
+            evaluation.status == EvaluationStatus::Submitted || evaluation.status == EvaluationStatus::Challenged,
+            OracleError::EvaluationNotChallengeable
+        );
+
+        // Each further challenger must put up more than everyone already challenging
+        let open_challenges = self.open_challenges(&evaluation_id);
+        ensure!(
+            !open_challenges.iter().any(|open| open.challenger == challenger),
+            OracleError::DuplicateChallenge
+        );
+        let prior_stake: Balance = open_challenges.iter().map(|open| open.stake).sum();
+        ensure!(
+            challenge_stake > prior_stake,
+            OracleError::ChallengeStakeNotEscalated,
+            "{} already staked",
+            prior_stake
+        );
+
+        // Check if challenge period is still open
+        let challenge_deadline = self.challenge_deadline(&evaluation);
+        ensure!(env::block_timestamp() <= challenge_deadline, OracleError::ChallengePeriodExpired);
+
+        self.challenge_counter += 1;
+        let challenge_id = format!("challenge_{}", self.challenge_counter);
+        
+        let challenge = RefutationChallenge {
+            challenge_id: challenge_id.clone(),
+            evaluation_id: evaluation_id.clone(),
+            challenger: challenger.clone(),
+            counter_sources,
+            stake: challenge_stake,
+            status: ChallengeStatus::Submitted,
+            submitted_at: U64(env::block_timestamp()),
+            rebuttal_sources: vec![],
+            settled_at: None,
+            settled_winner: None,
+            settlement_payout: 0,
+            appeal_votes: vec![],
+        };
+
+        self.challenges.insert(&challenge_id, &challenge);
+        self.total_escrowed += challenge_stake;
+        Self::push_indexed_id(&mut self.solver_challenges, &evaluation.solver, &challenge_id, |account_hash| {
+            StorageKey::SolverChallengeIds { account_hash }
+        });
+        let mut evaluation_challenges = self.evaluation_challenges.get(&evaluation_id)
+            .unwrap_or_else(|| Vector::new(StorageKey::EvaluationChallengeIds {
+                evaluation_hash: env::sha256(evaluation_id.as_bytes()),
+            }));
+        evaluation_challenges.push(&challenge_id);
+        self.evaluation_challenges.insert(&evaluation_id, &evaluation_challenges);
+        
+        // Update evaluation status
+        let mut updated_evaluation = evaluation;
+        if updated_evaluation.status == EvaluationStatus::Submitted {
+            self.set_evaluation_status(&mut updated_evaluation, EvaluationStatus::Challenged);
+            self.evaluations.insert(&evaluation_id, &updated_evaluation);
+        }
+        if let Some(mut intent) = self.intents.get(&updated_evaluation.intent_id) {
+            // The intent points at the earliest open challenge
+            if open_challenges.is_empty() {
+                intent.challenge_hash = Some(challenge_id.clone());
+            }
+            self.intents.insert(&intent.intent_id, &intent);
+            self.record_intent_history(
+                &intent.intent_id,
+                &challenger,
+                format!("challenge_submitted:{}", challenge_id),
+                &intent.status,
+            );
+        }
+
+        env::log_str(&format!(
+            "Challenge {} submitted by {} for evaluation {}", 
+            challenge_id, challenger, evaluation_id
+        ));
+
+        challenge_id
+    }
+
+    /// Rebut a challenge with counter-counter-evidence (evaluation's solver only)
+    pub fn submit_counter_refutation(&mut self, challenge_id: String, counter_sources: Vec<Source>) {
+        self.assert_not_paused();
+        let caller = env::predecessor_account_id();
+
+        let mut challenge = self.challenges.get(&challenge_id)
+            .expect("Challenge not found");
+        let evaluation = self.evaluations.get(&challenge.evaluation_id)
+            .expect("Evaluation not found");
+
+        require!(evaluation.solver == caller, "Only the challenged solver can submit a rebuttal");
+        require!(
+            challenge.status == ChallengeStatus::Submitted || challenge.status == ChallengeStatus::UnderReview,
+            "Challenge is no longer open for rebuttal"
+        );
+        Self::assert_valid_sources(&counter_sources);
+        self.assert_allowed_domains(&counter_sources);
+
+        challenge.rebuttal_sources = counter_sources;
+        challenge.status = ChallengeStatus::UnderReview;
+        self.challenges.insert(&challenge_id, &challenge);
+
+        env::log_str(&format!(
+            "Counter-refutation submitted by {} for challenge {}",
+            caller, challenge_id
+        ));
+    }
+
+    /// Withdraw a challenge before the solver rebuts it or verifiers start voting (challenger
+    /// only). The stake is refunded less the withdrawal penalty, and once no other challenge
+    /// is open the evaluation can be finalized again.
+    pub fn withdraw_challenge(&mut self, challenge_id: String) {
+        self.assert_not_paused();
+        let caller = env::predecessor_account_id();
+
+        let mut challenge = self.challenges.get(&challenge_id)
+            .expect("Challenge not found");
+        require!(challenge.challenger == caller, "Only the challenger can withdraw a challenge");
+        require!(
+            challenge.status == ChallengeStatus::Submitted,
+            "Challenge is already under review"
+        );
+        let mut evaluation = self.evaluations.get(&challenge.evaluation_id)
+            .expect("Evaluation not found");
+        require!(
+            evaluation.status == EvaluationStatus::Challenged,
+            "Evaluation is not in challenged state"
+        );
+
+        let penalty = challenge.stake * self.challenge_withdrawal_penalty_bps as u128 / BPS_DENOMINATOR;
+        let refund = challenge.stake - penalty;
+        self.protocol_treasury += penalty;
+        self.credit_withdrawal(&caller, refund);
+
+        challenge.status = ChallengeStatus::Withdrawn;
+        challenge.settled_at = Some(U64(env::block_timestamp()));
+        self.challenges.insert(&challenge_id, &challenge);
+
+        let remaining = self.open_challenges(&evaluation.evaluation_id);
+        if remaining.is_empty() {
+            self.set_evaluation_status(&mut evaluation, EvaluationStatus::Submitted);
+            self.evaluations.insert(&evaluation.evaluation_id, &evaluation);
+        }
+        if let Some(mut intent) = self.intents.get(&evaluation.intent_id) {
+            if intent.challenge_hash.as_ref() == Some(&challenge_id) {
+                intent.challenge_hash = remaining.first().map(|open| open.challenge_id.clone());
+            }
+            self.intents.insert(&intent.intent_id, &intent);
+            self.record_intent_history(
+                &intent.intent_id,
+                &caller,
+                format!("challenge_withdrawn:{}", challenge_id),
+                &intent.status,
+            );
+        }
+
+        env::log_str(&format!(
+            "ChallengeWithdrawn: {} by {} for evaluation {}, refund {}, penalty {}",
+            challenge_id, caller, evaluation.evaluation_id, refund, penalty
+        ));
+    }
+
+    /// Resolve a dispute the owner has left unsettled past `dispute_settlement_deadline`
+    /// (anyone may call). The dispute is settled as a tie: every open challenge on the
+    /// evaluation is marked `Failed`, all stakes are returned to their owners, and a
+    /// single-answer intent's stake goes back to its initiator.
+    pub fn force_resolve_stale_dispute(&mut self, challenge_id: String) {
+        self.assert_not_paused();
+        let challenge = self.challenges.get(&challenge_id)
+            .expect("Challenge not found");
+        require!(Self::is_open_challenge(&challenge), "Challenge is no longer open");
+        let deadline = challenge.submitted_at.0.saturating_add(self.dispute_settlement_deadline.0);
+        require!(
+            env::block_timestamp() > deadline,
+            "Dispute is still within its settlement deadline"
+        );
+
+        self.internal_settle_dispute(challenge.evaluation_id.clone(), challenge_id.clone(), "tie", 0);
+
+        env::log_str(&format!(
+            "DisputeTimedOut: {} on evaluation {} resolved by {}, deadline {}",
+            challenge_id, challenge.evaluation_id, env::predecessor_account_id(), deadline
+        ));
+    }
+
+    /// Settle a dispute between an evaluation and its challenges. Every open challenge on the
+    /// evaluation is resolved together with `challenge_id`.
+    pub fn settle_dispute(
+        &mut self,
+        evaluation_id: String,
+        challenge_id: String,
+        winner: String, // "evaluator", "challenger", or "tie"
+    ) {
+        self.assert_not_paused();
+        self.assert_owner();
+        self.internal_settle_dispute(evaluation_id, challenge_id, &winner, 0);
+    }
+
+    /// Settle a dispute that both sides got partly right. `evaluator_share_bps` is how far the
+    /// evaluator was right: 10000, 0 and 5000 settle exactly like an evaluator win, challenger
+    /// win and tie. Between those, each side's stake is returned and the side found more wrong
+    /// pays over part of its stake in proportion to how lopsided the split is; no slashing
+    /// applies. Reputations move by each side's share. Partial outcomes cannot be appealed.
+    pub fn settle_dispute_partial(&mut self, evaluation_id: String, challenge_id: String, evaluator_share_bps: u16) {
+        self.assert_not_paused();
+        self.assert_owner();
+        require!(evaluator_share_bps as u128 <= BPS_DENOMINATOR, "Evaluator share cannot exceed 10000 bps");
+
+        match evaluator_share_bps {
+            10_000 => { self.internal_settle_dispute(evaluation_id, challenge_id, "evaluator", 0); },
+            0 => { self.internal_settle_dispute(evaluation_id, challenge_id, "challenger", 0); },
+            5_000 => { self.internal_settle_dispute(evaluation_id, challenge_id, "tie", 0); },
+            _ => self.internal_settle_dispute_partial(evaluation_id, challenge_id, evaluator_share_bps),
+        }
+    }
+
+    /// Settle a dispute by comparing the summed domain reliability of the evaluation's sources
+    /// against the strongest open challenge's (owner, admin or verifier only). Each host counts
+    /// once per side. Equal reliability falls back to a tie, returning all stakes.
+    pub fn auto_settle_dispute(&mut self, evaluation_id: String, challenge_id: String) {
+        self.assert_not_paused();
+        self.assert_verifier_or_admin();
+        let (evaluation, challenges) = self.open_dispute(&evaluation_id, &challenge_id);
+
+        let evaluator_reliability = self.sources_reliability(&evaluation.sources);
+        let challenger_reliability = challenges
+            .iter()
+            .map(|challenge| self.sources_reliability(&challenge.counter_sources))
+            .fold(0.0, f64::max);
+
+        let winner = if evaluator_reliability > challenger_reliability {
+            "evaluator"
+        } else if challenger_reliability > evaluator_reliability {
+            "challenger"
+        } else {
+            "tie"
+        };
+
+        env::log_str(&format!(
+            "Auto-settling dispute {}: evaluator reliability {}, challenger reliability {}",
+            challenge_id, evaluator_reliability, challenger_reliability
+        ));
+
+        self.internal_settle_dispute(evaluation_id, challenge_id, winner, 0);
+    }
+
+    /// Settle a dispute, withholding `verifier_fee_bps` of the losing side's stake from the
+    /// winners' payout. Returns the amount withheld, which the caller must distribute.
+    /// The winner takes `dispute_winner_bps` of what is left of the losing stake and the loser
+    /// gets the remainder back. Challengers win or lose together: when they win, each gets
+    /// their stake back plus a share of the winnings (and slash) in proportion to their
+    /// challenge stake.
+    fn internal_settle_dispute(
+        &mut self,
+        evaluation_id: String,
+        challenge_id: String,
+        winner: &str,
+        verifier_fee_bps: u128,
+    ) -> Balance {
+        let (mut evaluation, challenges) = self.open_dispute(&evaluation_id, &challenge_id);
+        let challenge_stakes: Vec<Balance> = challenges.iter().map(|challenge| challenge.stake).collect();
+        let total_challenge_stake: Balance = challenge_stakes.iter().sum();
+
+        let losing_stake = match winner {
+            "evaluator" => total_challenge_stake,
+            "challenger" => evaluation.stake,
+            _ => 0,
+        };
+        let verifier_fee = losing_stake * verifier_fee_bps / BPS_DENOMINATOR;
+        let protocol_fee = (losing_stake - verifier_fee) * self.protocol_fee_bps as u128 / BPS_DENOMINATOR;
+        self.protocol_treasury += protocol_fee;
+        let forfeited = losing_stake - verifier_fee - protocol_fee;
+        let winner_take = forfeited * self.dispute_winner_bps as u128 / BPS_DENOMINATOR;
+        let returned = forfeited - winner_take;
+        let weight = self.reputation_weight(&evaluation.intent_id);
+        
+        let payouts = match winner {
+            "evaluator" => {
+                // Evaluator wins, gets their stake back + the winner's share of every challenge stake
+                let total_stake = evaluation.stake + winner_take;
+                self.credit_reward(&evaluation.solver, total_stake);
+                if returned > 0 {
+                    let refunds = Self::split_pro_rata(returned, &challenge_stakes);
+                    for (challenge, refund) in challenges.iter().zip(refunds) {
+                        self.credit_withdrawal(&challenge.challenger, refund);
+                    }
+                }
+                self.record_evaluation_outcome(&mut evaluation, true);
+                for challenge in &challenges {
+                    self.update_solver_challenge_metrics(&evaluation.solver, true);
+                    self.update_solver_reputation(&challenge.challenger, false, weight);
+                }
+                
+                // Update performance metrics for successful defense
+                self.update_solver_performance_metrics(
+                    &evaluation.solver,
+                    evaluation.execution_time.0 as f64,
+                    evaluation.confidence,
+                    evaluation.sources.len() as u64,
+                    total_stake
+                );
+
+                // Attribute the payout to each challenge by stake, so an appeal only claws back its part
+                Self::split_pro_rata(total_stake, &challenge_stakes)
+            },
+            "challenger" => {
+                self.update_solver_challenge_metrics(&evaluation.solver, false);
+                self.record_evaluation_outcome(&mut evaluation, false);
+                
+                // Track lost stakes for the evaluator
+                if let Some(mut solver) = self.solvers.get(&evaluation.solver) {
+                    solver.performance_metrics.total_stakes_lost += evaluation.stake - returned;
+                    self.solvers.insert(&evaluation.solver, &solver);
+                }
+                if returned > 0 {
+                    self.credit_withdrawal(&evaluation.solver, returned);
+                }
+
+                // Slash part of the evaluator's registration stake and award it to the challengers
+                let registration_stake = self.solver_stakes.get(&evaluation.solver).unwrap_or(0);
+                let slash_amount = registration_stake * self.slash_fraction_bps as u128 / BPS_DENOMINATOR;
+                let slashed = self.slash_solver(
+                    evaluation.solver.clone(),
+                    slash_amount,
+                    format!("lost dispute {}", challenge_id),
+                );
+
+                // Challengers get their stake back + a pro-rata share of the evaluation stake
+                let winnings = winner_take + slashed;
+                let shares = Self::split_pro_rata(winnings, &challenge_stakes);
+                let mut payouts = Vec::with_capacity(challenges.len());
+                for (challenge, share) in challenges.iter().zip(shares) {
+                    self.credit_reward(&challenge.challenger, challenge.stake + share);
+                    self.update_solver_reputation(&challenge.challenger, true, weight);
+                    payouts.push(challenge.stake + share);
+                }
+                self.deactivate_if_understaked(&evaluation.solver);
+                payouts
+            },
+            "tie" => {
+                // Tie, everyone gets their stake back
+                self.credit_withdrawal(&evaluation.solver, evaluation.stake);
+                for challenge in &challenges {
+                    self.credit_withdrawal(&challenge.challenger, challenge.stake);
+                }
+                vec![0; challenges.len()]
+            },
+            _ => env::panic_str("Invalid winner specification"),
+        };
+
+        // Close out the dispute so it cannot be settled twice
+        let challenge_status = if winner == "challenger" {
+            self.set_evaluation_status(&mut evaluation, EvaluationStatus::Refuted);
+            self.start_dispute_cooldown(&evaluation.solver, &evaluation.intent_id);
+            ChallengeStatus::Successful
+        } else {
+            self.set_evaluation_status(&mut evaluation, EvaluationStatus::Verified);
+            ChallengeStatus::Failed
+        };
+        let challenge_count = challenges.len();
+        self.close_dispute(evaluation, challenges, payouts, challenge_status, winner);
+
+        env::log_str(&format!(
+            "Dispute settled: {} wins against {} challenge(s), protocol fee {}",
+            winner, challenge_count, protocol_fee
+        ));
+        verifier_fee
+    }
+
+    /// Split a dispute that is neither a clean win nor a tie. Each side keeps its stake, and
+    /// the side found more wrong hands over the fraction `|2 * share - 1|` of its stake.
+    fn internal_settle_dispute_partial(&mut self, evaluation_id: String, challenge_id: String, evaluator_share_bps: u16) {
+        let (mut evaluation, challenges) = self.open_dispute(&evaluation_id, &challenge_id);
+
+        let share_bps = evaluator_share_bps as u128;
+        let evaluator_ahead = share_bps * 2 > BPS_DENOMINATOR;
+        let transfer_bps = share_bps.abs_diff(BPS_DENOMINATOR - share_bps);
+        let fee_bps = self.protocol_fee_bps as u128;
+        // Floor each transfer so the payer keeps any remainder and no yocto is lost
+        let (payouts, transferred, protocol_fee) = if evaluator_ahead {
+            let mut payouts = Vec::with_capacity(challenges.len());
+            let mut transferred = 0;
+            let mut protocol_fee = 0;
+            for challenge in &challenges {
+                let transfer = challenge.stake * transfer_bps / BPS_DENOMINATOR;
+                let fee = transfer * fee_bps / BPS_DENOMINATOR;
+                self.credit_withdrawal(&challenge.challenger, challenge.stake - transfer);
+                transferred += transfer;
+                protocol_fee += fee;
+                payouts.push(transfer - fee);
+            }
+            self.credit_withdrawal(&evaluation.solver, evaluation.stake + transferred - protocol_fee);
+            (payouts, transferred, protocol_fee)
+        } else {
+            let transfer = evaluation.stake * transfer_bps / BPS_DENOMINATOR;
+            let protocol_fee = transfer * fee_bps / BPS_DENOMINATOR;
+            self.credit_withdrawal(&evaluation.solver, evaluation.stake - transfer);
+            let challenge_stakes: Vec<Balance> = challenges.iter().map(|challenge| challenge.stake).collect();
+            let shares = Self::split_pro_rata(transfer - protocol_fee, &challenge_stakes);
+            for (challenge, share) in challenges.iter().zip(&shares) {
+                self.credit_withdrawal(&challenge.challenger, challenge.stake + share);
+            }
+            (shares, transfer, protocol_fee)
+        };
+        self.protocol_treasury += protocol_fee;
+
+        let evaluator_share = evaluator_share_bps as f64 / BPS_DENOMINATOR as f64;
+        let weight = self.reputation_weight(&evaluation.intent_id);
+        self.record_evaluation_outcome_share(&mut evaluation, evaluator_share);
+        for challenge in &challenges {
+            self.update_solver_reputation_share(&challenge.challenger, 1.0 - evaluator_share, weight);
+            self.update_solver_challenge_metrics(&evaluation.solver, evaluator_ahead);
+        }
+
+        let challenge_status = if evaluator_ahead {
+            self.set_evaluation_status(&mut evaluation, EvaluationStatus::Verified);
+            ChallengeStatus::Failed
+        } else {
+            self.set_evaluation_status(&mut evaluation, EvaluationStatus::Refuted);
+            self.start_dispute_cooldown(&evaluation.solver, &evaluation.intent_id);
+            ChallengeStatus::Successful
+        };
+        self.close_dispute(evaluation, challenges, payouts, challenge_status, "partial");
+
+        env::log_str(&format!(
+            "Dispute {} settled partially: evaluator share {} bps, {} moved to the {}, protocol fee {}",
+            challenge_id,
+            evaluator_share_bps,
+            transferred,
+            if evaluator_ahead { "evaluator" } else { "challengers" },
+            protocol_fee
+        ));
+    }
+
+    /// Load an evaluation under dispute and every open challenge against it, oldest first.
+    /// `challenge_id` must be one of them.
+    fn open_dispute(&self, evaluation_id: &String, challenge_id: &String) -> (OracleEvaluation, Vec<RefutationChallenge>) {
+        let evaluation = self.evaluations.get(evaluation_id)
+            .expect("Evaluation not found");
+        let challenge = self.challenges.get(challenge_id)
+            .expect("Challenge not found");
+        
+        require!(challenge.evaluation_id == *evaluation_id, "Challenge does not match evaluation");
+        require!(
+            evaluation.status == EvaluationStatus::Challenged,
+            "Evaluation is not in challenged state"
+        );
+        require!(Self::is_open_challenge(&challenge), "Challenge is no longer open");
+
+        let mut challenges = self.open_challenges(evaluation_id);
+        // Challenges raised before the per-evaluation index existed are not listed in it
+        if !challenges.iter().any(|open| open.challenge_id == *challenge_id) {
+            challenges.push(challenge);
+        }
+        (evaluation, challenges)
+    }
+
+    /// Record a settlement on each challenge, release the intent's escrow unless the
+    /// evaluation was refuted, and move the intent to `Settled`
+    fn close_dispute(
+        &mut self,
+        evaluation: OracleEvaluation,
+        challenges: Vec<RefutationChallenge>,
+        payouts: Vec<Balance>,
+        status: ChallengeStatus,
+        winner: &str,
+    ) {
+        self.release_active_evaluation(&evaluation.solver);
+        if matches!(winner, "evaluator" | "challenger") && challenges.len() == 1 {
+            let until = env::block_timestamp() + self.appeal_period.0;
+            self.lock_stake_until(&evaluation.solver, until);
+            self.lock_stake_until(&challenges[0].challenger, until);
+        }
+        for (mut challenge, settlement_payout) in challenges.into_iter().zip(payouts) {
+            challenge.status = status.clone();
+            challenge.settled_at = Some(U64(env::block_timestamp()));
+            challenge.settled_winner = Some(winner.to_string());
+            challenge.settlement_payout = settlement_payout;
+            self.total_rewards_distributed += settlement_payout;
+            self.challenges.insert(&challenge.challenge_id, &challenge);
+        }
+        self.evaluations.insert(&evaluation.evaluation_id, &evaluation);
+
+        if let Some(mut intent) = self.intents.get(&evaluation.intent_id) {
+            self.release_disputed_intent(&intent, &evaluation, winner);
+            // A disputed answer may still be reversed on appeal, so duplicates go back to solvers
+            self.forget_intent_links(&intent.intent_id);
+            self.set_intent_status(&mut intent, IntentStatus::Settled);
+            self.intents.insert(&evaluation.intent_id, &intent);
+            self.record_intent_history(
+                &intent.intent_id,
+                &env::predecessor_account_id(),
+                format!("dispute_settled:{}", winner),
+                &intent.status,
+            );
+        }
+    }
+
+    /// Vote on a challenged evaluation (staked verifiers only). Once a majority of verifiers
+    /// agree, the dispute settles; majority voters share a fraction of the losing stake, and
+    /// verifiers who keep voting against the outcome are slashed.
+    pub fn resolve_dispute_by_vote(&mut self, evaluation_id: String, challenge_id: String, winner: String) {
+        self.assert_not_paused();
+        let caller = env::predecessor_account_id();
+        require!(
+            self.verifiers.iter().any(|verifier| verifier == caller),
+            "Only verifiers can vote on disputes"
+        );
+        require!(
+            self.verifier_stakes.get(&caller).unwrap_or(0) >= self.min_stake,
+            "Verifier stake is below the minimum"
+        );
+        require!(
+            matches!(winner.as_str(), "evaluator" | "challenger" | "tie"),
+            "Invalid winner specification"
+        );
+
+        let mut challenge = self.challenges.get(&challenge_id)
+            .expect("Challenge not found");
+        require!(challenge.evaluation_id == evaluation_id, "Challenge does not match evaluation");
+
+        let mut votes = self.dispute_votes.get(&challenge_id).unwrap_or_default();
+        require!(
+            !votes.iter().any(|(verifier, _)| *verifier == caller),
+            "Verifier has already voted on this dispute"
+        );
+        votes.push((caller.clone(), winner.clone()));
+        env::log_str(&format!("Verifier {} voted {} on dispute {}", caller, winner, challenge_id));
+
+        let agreeing = votes.iter().filter(|(_, vote)| *vote == winner).count() as u64;
+        if agreeing * 2 <= self.verifiers.len() {
+            self.dispute_votes.insert(&challenge_id, &votes);
+            if challenge.status == ChallengeStatus::Submitted {
+                challenge.status = ChallengeStatus::UnderReview;
+                self.challenges.insert(&challenge_id, &challenge);
+            }
+            return;
+        }
+
+        let verifier_fee = self.internal_settle_dispute(evaluation_id, challenge_id.clone(), &winner, VERIFIER_REWARD_BPS);
+        self.dispute_votes.remove(&challenge_id);
+
+        // Share the fee among majority voters; the last one absorbs rounding
+        let mut remaining_fee = verifier_fee;
+        let mut majority_seen = 0;
+        for (verifier, vote) in votes {
+            let mut record = self.verifier_records.get(&verifier).unwrap_or_default();
+            record.votes_cast += 1;
+
+            if vote == winner {
+                record.majority_votes += 1;
+                majority_seen += 1;
+                let share = if majority_seen == agreeing {
+                    remaining_fee
+                } else {
+                    verifier_fee / agreeing as u128
+                };
+                remaining_fee -= share;
+                if share > 0 {
+                    self.credit_withdrawal(&verifier, share);
+                }
+            } else if record.votes_cast >= VERIFIER_SLASH_MIN_VOTES && record.majority_votes * 2 < record.votes_cast {
+                self.slash_verifier(&verifier, format!("low agreement rate after dispute {}", challenge_id));
+            }
+
+            self.verifier_records.insert(&verifier, &record);
+        }
+    }
+
+    /// Add the attached deposit to the caller's verifier stake
+    #[payable]
+    pub fn add_verifier_stake(&mut self) -> U128 {
+        self.assert_not_paused();
+        let caller = env::predecessor_account_id();
+        require!(
+            self.verifiers.iter().any(|verifier| verifier == caller),
+            "Only verifiers can stake as verifiers"
+        );
+        let amount = self.native_stake_deposit();
+        require!(amount > 0, "Attach a non-zero deposit to add stake");
+
+        let new_total = self.verifier_stakes.get(&caller).unwrap_or(0) + amount;
+        self.verifier_stakes.insert(&caller, &new_total);
+        self.total_escrowed += amount;
+
+        env::log_str(&format!("Verifier {} added stake {}, total stake {}", caller, amount, new_total));
+        U128(new_total)
+    }
+
+    /// Appeal a settled dispute (losing party only, within the appeal period). A settlement
+    /// that resolved several challenges at once cannot be appealed.
+    pub fn appeal_dispute(&mut self, challenge_id: String) {
+        self.assert_not_paused();
+        let caller = env::predecessor_account_id();
+        let mut challenge = self.challenges.get(&challenge_id)
+            .expect("Challenge not found");
+        let evaluation = self.evaluations.get(&challenge.evaluation_id)
+            .expect("Evaluation not found");
+
+        let settled_at = challenge.settled_at.expect("Dispute has not been settled");
+        require!(
+            env::block_timestamp() <= settled_at.0 + self.appeal_period.0,
+            "Appeal period has expired"
+        );
+        let loser = match challenge.settled_winner.as_deref() {
+            Some("evaluator") => &challenge.challenger,
+            Some("challenger") => &evaluation.solver,
+            _ => env::panic_str("Only a decided dispute can be appealed"),
+        };
+        require!(
+            challenge.status != ChallengeStatus::Appealed && challenge.appeal_votes.is_empty(),
+            "Dispute is already under appeal or resolved"
+        );
+        require!(*loser == caller, "Only the losing party can appeal");
+        // Challenges settled together share one evaluation outcome, which one appeal cannot flip alone
+        let settled_together = self.get_evaluation_challenges(evaluation.evaluation_id.clone())
+            .iter()
+            .filter(|settled| settled.settled_at == Some(settled_at))
+            .count();
+        require!(settled_together <= 1, "Disputes settled together with other challenges cannot be appealed");
+
+        challenge.status = ChallengeStatus::Appealed;
+        self.challenges.insert(&challenge_id, &challenge);
+        self.adjust_open_appeal_count(&evaluation.solver, true);
+        self.adjust_open_appeal_count(&challenge.challenger, true);
+
+        // Keep the intent out of cleanup while the appeal is open
+        if let Some(mut intent) = self.intents.get(&evaluation.intent_id) {
+            self.set_intent_status(&mut intent, IntentStatus::Disputed);
+            self.intents.insert(&evaluation.intent_id, &intent);
+        }
+
+        env::log_str(&format!("Dispute {} appealed by {}", challenge_id, caller));
+    }
+
+    /// Vote on an appealed dispute (staked verifiers only); a majority of verifiers either
+    /// upholds or reverses the outcome
+    pub fn resolve_appeal(&mut self, challenge_id: String, uphold: bool) {
+        self.assert_not_paused();
+        let caller = env::predecessor_account_id();
+        require!(
+            self.verifiers.iter().any(|verifier| verifier == caller),
+            "Only verifiers can resolve appeals"
+        );
+        require!(
+            self.verifier_stakes.get(&caller).unwrap_or(0) >= self.min_stake,
+            "Verifier stake is below the minimum"
+        );
+
+        let mut challenge = self.challenges.get(&challenge_id)
+            .expect("Challenge not found");
+        require!(challenge.status == ChallengeStatus::Appealed, "Dispute is not under appeal");
+        require!(
+            !challenge.appeal_votes.iter().any(|(verifier, _)| *verifier == caller),
+            "Verifier has already voted on this appeal"
+        );
+
+        challenge.appeal_votes.push((caller.clone(), uphold));
+        let votes = challenge.appeal_votes.iter().filter(|(_, vote)| *vote == uphold).count() as u64;
+        env::log_str(&format!("Verifier {} voted to {} appeal {}", caller, if uphold { "uphold" } else { "reverse" }, challenge_id));
+
+        if votes * 2 <= self.verifiers.len() {
+            self.challenges.insert(&challenge_id, &challenge);
+            return;
+        }
+
+        let mut evaluation = self.evaluations.get(&challenge.evaluation_id)
+            .expect("Evaluation not found");
+        let original_winner = challenge.settled_winner.clone().unwrap_or_default();
+        self.adjust_open_appeal_count(&evaluation.solver, false);
+        self.adjust_open_appeal_count(&challenge.challenger, false);
+
+        if uphold {
+            challenge.status = if original_winner == "challenger" {
+                ChallengeStatus::Successful
+            } else {
+                ChallengeStatus::Failed
+            };
+        } else {
+            // Claw the settlement payout back from the original winner's registration stake
+            // and pay it to the appellant; a winner without solver stake cannot be recovered from
+            let (from, to) = if original_winner == "challenger" {
+                self.set_evaluation_status(&mut evaluation, EvaluationStatus::Verified);
+                challenge.status = ChallengeStatus::Failed;
+                (challenge.challenger.clone(), evaluation.solver.clone())
+            } else {
+                self.set_evaluation_status(&mut evaluation, EvaluationStatus::Refuted);
+                challenge.status = ChallengeStatus::Successful;
+                self.start_dispute_cooldown(&evaluation.solver, &evaluation.intent_id);
+                (evaluation.solver.clone(), challenge.challenger.clone())
+            };
+
+            let recovered = self.claw_back_payout(&from, challenge.settlement_payout, format!("reversed appeal {}", challenge_id));
+            if recovered > 0 {
+                self.credit_withdrawal(&to, recovered);
+            }
+            if recovered < challenge.settlement_payout {
+                env::log_str(&format!(
+                    "Appeal {} reversal recovered {} of {} from {}",
+                    challenge_id, recovered, challenge.settlement_payout, from
+                ));
+            }
+            challenge.settled_winner = Some(if original_winner == "challenger" { "evaluator" } else { "challenger" }.to_string());
+            challenge.settlement_payout = recovered;
+            self.evaluations.insert(&evaluation.evaluation_id, &evaluation);
+        }
+        self.challenges.insert(&challenge_id, &challenge);
+
+        if let Some(mut intent) = self.intents.get(&evaluation.intent_id) {
+            if !uphold && intent.required_confirmations <= 1 {
+                if original_winner == "challenger" {
+                    // The evaluation stands after all, so its solver earns the still-escrowed reward
+                    self.release_disputed_intent(&intent, &evaluation, "evaluator");
+                } else {
+                    // Take the intent reward back from the solver; what is recovered funds a relist
+                    let (solver_share, _) = self.disputed_intent_share(&intent, &evaluation);
+                    let recovered = self.claw_back_payout(
+                        &evaluation.solver,
+                        solver_share,
+                        format!("reversed appeal {} intent reward", challenge_id),
+                    );
+                    intent.reward = recovered;
+                    intent.stake = recovered;
+                    env::log_str(&format!(
+                        "Intent {} keeps {} of reward {} for a relist",
+                        intent.intent_id, recovered, solver_share
+                    ));
+                }
+            }
+            self.set_intent_status(&mut intent, IntentStatus::Settled);
+            self.intents.insert(&evaluation.intent_id, &intent);
+        }
+
+        env::log_str(&format!(
+            "Appeal {} resolved: original outcome {}",
+            challenge_id, if uphold { "upheld" } else { "reversed" }
+        ));
+    }
+    
+    /// Pay `total_reward_pool` out of the bonus pool to active solvers with at least
+    /// `min_evaluations_for_pool` settled evaluations, in proportion to their weighted
+    /// performance score. Rounding dust goes to the highest-scoring solver.
+    pub fn distribute_performance_rewards(&mut self, total_reward_pool: U128) {
+        self.assert_owner();
+        self.internal_distribute_performance_rewards(total_reward_pool.0);
+    }
+
+    fn internal_distribute_performance_rewards(&mut self, pool: Balance) {
+        require!(pool > 0, "Reward pool must be positive");
+        require!(pool <= self.bonus_pool, "Reward pool exceeds the bonus pool");
+
+        let mut eligible: Vec<(OracleSolver, u128)> = self.active_solvers()
+            .into_iter()
+            .filter(|solver| solver.total_evaluations >= self.min_evaluations_for_pool)
+            .map(|solver| {
+                // Scores are small positive floats; scale them so shares use exact integer math
+                let weight = (self.calculate_weighted_performance_score(&solver) * BPS_DENOMINATOR as f64).max(0.0) as u128;
+                (solver, weight)
+            })
+            .collect();
+        let total_weight: u128 = eligible.iter().map(|(_, weight)| weight).sum();
+        require!(total_weight > 0, "No solvers are eligible for the performance pool");
+
+        let mut shares: Vec<Balance> = eligible.iter()
+            .map(|(_, weight)| pool * weight / total_weight)
+            .collect();
+        let top_index = (0..eligible.len())
+            .max_by_key(|index| eligible[*index].1)
+            .unwrap();
+        shares[top_index] += pool - shares.iter().sum::<Balance>();
+
+        self.bonus_pool -= pool;
+        self.total_rewards_distributed += pool;
+        for ((solver, _), share) in eligible.iter_mut().zip(shares) {
+            if share == 0 {
+                continue;
+            }
+            solver.performance_metrics.total_rewards_earned += share;
+            self.solvers.insert(&solver.solver_id, solver);
+            self.credit_withdrawal(&solver.solver_id, share);
+        }
+
+        env::log_str(&format!(
+            "Distributed {} performance reward pool across {} solvers",
+            pool,
+            eligible.len()
+        ));
+    }
+    
+    /// Calculate automatic reward for successful evaluation (no challenges). Anyone may call
+    /// this once the challenge period is over; the caller earns the keeper reward, which comes
+    /// out of the intent reward before the solver's share is computed.
+    pub fn finalize_evaluation_reward(&mut self, evaluation_id: String) -> Balance {
+        self.assert_not_paused();
+        let evaluation = self.evaluations.get(&evaluation_id)
+            .expect("Evaluation not found");
+            
+        require!(
+            evaluation.status == EvaluationStatus::Submitted,
+            "Evaluation already finalized"
+        );
+        require!(
+            evaluation.price_feed_result.as_ref().is_none_or(|result| result.agrees),
+            "Evaluation was flagged by a price feed and must be resolved through a challenge"
+        );
+        
+        // Check if challenge period has expired
+        let challenge_deadline = self.challenge_deadline(&evaluation);
+        require!(
+            env::block_timestamp() > challenge_deadline,
+            "Challenge period still active"
+        );
+        
+        let intent = self.intents.get(&evaluation.intent_id)
+            .expect("Intent not found");
+
+        // A quorum intent splits its reward among the evaluations that agreed; dissenting
+        // evaluations, and all of them if the intent closed without a quorum, only get their stake back
+        let intent_reward = if intent.required_confirmations > 1 {
+            let (confirming, _) = self.confirmation_status(&intent);
+            let quorum_reached = confirming.len() as u32 >= intent.required_confirmations;
+            require!(quorum_reached || intent.status != IntentStatus::Pending, "Quorum not yet reached");
+            match confirming.iter().position(|id| *id == evaluation_id) {
+                Some(index) if quorum_reached => {
+                    let share = intent.reward / confirming.len() as u128;
+                    if index == 0 { share + intent.reward % confirming.len() as u128 } else { share }
+                },
+                _ => return self.refund_quorum_evaluation(evaluation, quorum_reached),
+            }
+        } else {
+            intent.reward
+        };
+            
+        let earned_intent_reward = Self::earned_intent_reward(&intent, evaluation.confidence, intent_reward);
+        let initiator_refund = intent_reward - earned_intent_reward;
+
+        // The protocol's cut comes off the earned reward before any multipliers apply
+        let protocol_fee = earned_intent_reward * self.protocol_fee_bps as u128 / BPS_DENOMINATOR;
+        self.protocol_treasury += protocol_fee;
+        let keeper = env::predecessor_account_id();
+        let keeper_fee = self.keeper_reward.min(earned_intent_reward - protocol_fee);
+
+        // Calculate base reward, which is fully backed by the intent reward and solver stake
+        let base_reward = earned_intent_reward - protocol_fee - keeper_fee + evaluation.stake;
+        let mut total_reward = base_reward;
+        
+        // Apply reputation multiplier
+        if let Some(solver) = self.solvers.get(&evaluation.solver) {
+            let reputation_multiplier = Self::finite_or(1.0 + (solver.reputation_score - 0.5) * 0.5, 1.0); // 0.75x to 1.25x
+            total_reward = (total_reward as f64 * reputation_multiplier) as Balance;
+            
+            // Apply performance bonus for fast execution
+            let speed_bonus = self.compute_speed_bonus(evaluation.execution_time);
+            if speed_bonus > 0.0 {
+                total_reward = (total_reward as f64 * (1.0 + speed_bonus)) as Balance;
+            }
+        }
+
+        // Evaluations resting mostly on sources other confirmed evaluations also cited earn a
+        // further bonus; it is funded from the bonus pool like the others
+        if self.is_well_corroborated(&evaluation.sources) {
+            total_reward += total_reward * self.corroboration_bonus_bps as u128 / BPS_DENOMINATOR;
+        }
+
+        // Anything above the base is paid from the bonus pool, capped at what the pool holds;
+        // a below-base payout returns the withheld amount to the pool
+        if total_reward > base_reward {
+            let bonus = total_reward - base_reward;
+            let funded_bonus = bonus.min(self.bonus_pool);
+            self.bonus_pool -= funded_bonus;
+            total_reward = base_reward + funded_bonus;
+
+            if funded_bonus < bonus {
+                env::log_str(&format!(
+                    "Bonus pool shortfall of {} for evaluation {}",
+                    bonus - funded_bonus, evaluation_id
+                ));
+            }
+        } else {
+            self.bonus_pool += base_reward - total_reward;
+        }
+        
+        // Split the reward among contributors by weight; the last one absorbs rounding
+        let mut payouts = Vec::new();
+        if evaluation.contributors.is_empty() {
+            payouts.push((evaluation.solver.clone(), total_reward));
+        } else {
+            let mut remaining = total_reward;
+            for (index, (contributor, weight)) in evaluation.contributors.iter().enumerate() {
+                let share = if index + 1 == evaluation.contributors.len() {
+                    remaining
+                } else {
+                    ((total_reward as f64 * weight) as Balance).min(remaining)
+                };
+                remaining -= share;
+                payouts.push((contributor.clone(), share));
+            }
+        }
+
+        // Transfer reward
+        self.total_rewards_distributed += total_reward;
+        for (recipient, share) in &payouts {
+            self.credit_reward(recipient, *share);
+        }
+        if initiator_refund > 0 {
+            self.credit_withdrawal(&intent.initiator, initiator_refund);
+            env::log_str(&format!(
+                "Credited {} refund to {} for confidence below threshold",
+                initiator_refund, intent.initiator
+            ));
+        }
+        if keeper_fee > 0 {
+            self.credit_withdrawal(&keeper, keeper_fee);
+            env::log_str(&format!("KeeperRewarded: {} earned {} for finalizing {}", keeper, keeper_fee, evaluation_id));
+        }
+        
+        // Update evaluation status
+        let mut updated_evaluation = evaluation;
+        self.set_evaluation_status(&mut updated_evaluation, EvaluationStatus::Confirmed);
+        self.release_active_evaluation(&updated_evaluation.solver);
+        self.record_evaluation_outcome(&mut updated_evaluation, true);
+        self.evaluations.insert(&evaluation_id, &updated_evaluation);
+        self.record_source_corroboration(&updated_evaluation.sources);
+        
+        // Update solver performance metrics
+        for (recipient, share) in payouts {
+            self.update_solver_performance_metrics(
+                &recipient,
+                updated_evaluation.execution_time.0 as f64,
+                updated_evaluation.confidence,
+                updated_evaluation.sources.len() as u64,
+                share
+            );
+        }
+        self.record_intent_history(
+            &intent.intent_id,
+            &keeper,
+            format!("reward_finalized:{}", evaluation_id),
+            &intent.status,
+        );
+        self.reward_referrer(&intent.initiator);
+        self.resolve_linked_duplicates(&intent.intent_id, &updated_evaluation);
+        
+        env::log_str(&format!(
+            "Evaluation {} finalized with reward {} for solver {}, protocol fee {}", 
+            evaluation_id, 
+            total_reward, 
+            updated_evaluation.solver,
+            protocol_fee
+        ));
+        
+        total_reward
+    }
+
+    /// Share of `intent_reward` an evaluation at `confidence` earns. An answer less confident
+    /// than the initiator asked for earns a proportional share; the rest is refunded to the initiator.
+    fn earned_intent_reward(intent: &OracleIntent, confidence: f64, intent_reward: Balance) -> Balance {
+        match intent.confidence_threshold {
+            Some(threshold) if confidence < threshold => {
+                let share_bps = ((confidence / threshold) * BPS_DENOMINATOR as f64) as u128;
+                intent_reward * share_bps.min(BPS_DENOMINATOR) / BPS_DENOMINATOR
+            },
+            _ => intent_reward,
+        }
+    }
+
+    /// Solver's part of a disputed single-answer intent's reward: the earned share, less the
+    /// protocol fee at the current rate
+    fn disputed_intent_share(&self, intent: &OracleIntent, evaluation: &OracleEvaluation) -> (Balance, Balance) {
+        let earned = Self::earned_intent_reward(intent, evaluation.confidence, intent.reward);
+        let protocol_fee = earned * self.protocol_fee_bps as u128 / BPS_DENOMINATOR;
+        (earned - protocol_fee, protocol_fee)
+    }
+
+    /// Release a single-answer intent's escrow once its dispute is decided. A defended evaluation
+    /// earns the reward with the same confidence share and protocol fee as finalization, and a tie
+    /// refunds the whole stake to the initiator. A refuted evaluation leaves the escrow in place
+    /// for a relist; quorum intents pay out through finalization.
+    fn release_disputed_intent(&mut self, intent: &OracleIntent, evaluation: &OracleEvaluation, winner: &str) {
+        if intent.required_confirmations > 1 || evaluation.status == EvaluationStatus::Refuted {
+            return;
+        }
+        if winner == "tie" {
+            self.credit_withdrawal(&intent.initiator, intent.stake);
+            env::log_str(&format!(
+                "Intent {} stake {} returned to {} after a tied dispute",
+                intent.intent_id, intent.stake, intent.initiator
+            ));
+            return;
+        }
+
+        let (solver_share, protocol_fee) = self.disputed_intent_share(intent, evaluation);
+        self.protocol_treasury += protocol_fee;
+        let initiator_refund = intent.stake - solver_share - protocol_fee;
+        if initiator_refund > 0 {
+            self.credit_withdrawal(&intent.initiator, initiator_refund);
+        }
+        self.credit_reward(&evaluation.solver, solver_share);
+        self.total_rewards_distributed += solver_share;
+        if let Some(mut solver) = self.solvers.get(&evaluation.solver) {
+            solver.performance_metrics.total_rewards_earned += solver_share;
+            self.solvers.insert(&evaluation.solver, &solver);
+        }
+
+        env::log_str(&format!(
+            "Intent {} reward {} paid to {} after a defended dispute, protocol fee {}, {} refunded to {}",
+            intent.intent_id, solver_share, evaluation.solver, protocol_fee, initiator_refund, intent.initiator
+        ));
+    }
+
+    /// Return a quorum evaluation's stake without a reward. When the quorum was reached the
+    /// evaluation dissented and counts as refuted; otherwise the intent closed without one.
+    fn refund_quorum_evaluation(&mut self, mut evaluation: OracleEvaluation, dissented: bool) -> Balance {
+        self.credit_withdrawal(&evaluation.solver, evaluation.stake);
+        if dissented {
+            self.set_evaluation_status(&mut evaluation, EvaluationStatus::Refuted);
+            self.record_evaluation_outcome(&mut evaluation, false);
+        } else {
+            self.set_evaluation_status(&mut evaluation, EvaluationStatus::Verified);
+        }
+        self.release_active_evaluation(&evaluation.solver);
+        self.evaluations.insert(&evaluation.evaluation_id, &evaluation);
+
+        env::log_str(&format!(
+            "Evaluation {} {}; stake {} returned to {}",
+            evaluation.evaluation_id,
+            if dissented { "dissented from the quorum" } else { "closed without a quorum" },
+            evaluation.stake,
+            evaluation.solver
+        ));
+        evaluation.stake
+    }
+
+    /// Check an evaluation's price claim against a price oracle's `get_price(pair)` (verifiers and admins).
+    /// The result is stored on the evaluation; a price that contradicts the answer flags it so it
+    /// cannot be finalized without going through a challenge.
+    pub fn verify_against_price_feed(&mut self, evaluation_id: String, feed_contract: AccountId, pair: String) -> Promise {
+        self.assert_not_paused();
+        self.assert_verifier_or_admin();
+        let evaluation = self.evaluations.get(&evaluation_id)
+            .expect("Evaluation not found");
+        require!(evaluation.status == EvaluationStatus::Submitted, "Evaluation is not awaiting verification");
+        require!(
+            evaluation.price_threshold.is_some() || matches!(evaluation.answer_value, Some(AnswerValue::Numeric(_))),
+            "Evaluation has no price claim"
+        );
+
+        Promise::new(feed_contract.clone())
+            .function_call(
+                "get_price".to_string(),
+                near_sdk::serde_json::json!({ "pair": pair }).to_string().into_bytes(),
+                NearToken::from_yoctonear(0),
+                PRICE_FEED_GAS,
+            )
+            .then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(PRICE_CALLBACK_GAS)
+                    .on_price_received(evaluation_id, feed_contract, pair),
+            )
+    }
+
+    /// Record a price feed answer against the evaluation's threshold. A failed or unreadable
+    /// feed response leaves the evaluation untouched.
+    #[private]
+    pub fn on_price_received(&mut self, evaluation_id: String, feed_contract: AccountId, pair: String) -> Option<PriceFeedResult> {
+        let price = match env::promise_result(0) {
+            PromiseResult::Successful(bytes) => Self::parse_feed_price(&bytes),
+            _ => None,
+        };
+        let Some(price) = price else {
+            env::log_str(&format!(
+                "Price feed {} gave no usable price for {}; evaluation {} unchanged",
+                feed_contract, pair, evaluation_id
+            ));
+            return None;
+        };
+
+        let mut evaluation = self.evaluations.get(&evaluation_id)?;
+        if evaluation.status != EvaluationStatus::Submitted {
+            return None;
+        }
+        // A numeric answer is checked against the price directly, a yes/no answer against its threshold
+        let (agrees, claim) = match (&evaluation.answer_value, evaluation.price_threshold) {
+            (Some(AnswerValue::Numeric(value)), _) => {
+                ((price - value).abs() <= self.answer_tolerance, format!("answer {}", value))
+            },
+            (_, Some(threshold)) => ((price >= threshold) == evaluation.answer, format!("threshold {}", threshold)),
+            _ => return None,
+        };
+
+        let result = PriceFeedResult {
+            feed_contract,
+            pair,
+            price,
+            agrees,
+            checked_at: U64(env::block_timestamp()),
+        };
+        evaluation.price_feed_result = Some(result.clone());
+        self.evaluations.insert(&evaluation_id, &evaluation);
+
+        env::log_str(&format!(
+            "{}: {} ({} price {} vs {})",
+            if result.agrees { "EvaluationPriceConfirmed" } else { "EvaluationPriceFlagged" },
+            evaluation_id, result.pair, price, claim
+        ));
+        Some(result)
+    }
+
+    /// Confirm a reward payout; a failed transfer is returned to escrow and made claimable
+    #[private]
+    pub fn on_reward_transferred(&mut self, recipient: AccountId, amount: U128, evaluation_id: Option<String>) -> bool {
+        if let PromiseResult::Successful(_) = env::promise_result(0) {
+            return true;
+        }
+
+        self.total_escrowed += amount.0;
+        let owed = self.failed_transfers.get(&recipient).unwrap_or(0);
+        self.failed_transfers.insert(&recipient, &(owed + amount.0));
+
+        env::log_str(&format!(
+            "Reward transfer of {} to {} failed{}; amount is claimable",
+            amount.0,
+            recipient,
+            evaluation_id.map(|id| format!(" for evaluation {}", id)).unwrap_or_default()
+        ));
+        false
+    }
+
+    /// Pay out everything the caller is owed from evaluation rewards, disputes and refunds
+    pub fn withdraw(&mut self) -> U128 {
+        self.assert_not_paused();
+        let account_id = env::predecessor_account_id();
+        let amount = self.pending_withdrawals.remove(&account_id).unwrap_or(0);
+        require!(amount > 0, "Nothing to withdraw");
+
+        self.transfer_reward_confirmed(&account_id, amount, None);
+
+        env::log_str(&format!("{} withdrew {}", account_id, amount));
+        U128(amount)
+    }
+
+    /// Pay out the caller's vesting tranches whose release time has passed
+    pub fn claim_vested(&mut self) -> U128 {
+        self.assert_not_paused();
+        let account_id = env::predecessor_account_id();
+        let mut schedule = self.vesting_schedule.get(&account_id).unwrap_or_default();
+        let now = env::block_timestamp();
+        let released: Balance = schedule.iter()
+            .filter(|(release_at, _)| release_at.0 <= now)
+            .map(|(_, tranche)| tranche)
+            .sum();
+        require!(released > 0, "Nothing has vested yet");
+
+        schedule.retain(|(release_at, _)| release_at.0 > now);
+        if schedule.is_empty() {
+            self.vesting_schedule.remove(&account_id);
+        } else {
+            self.vesting_schedule.insert(&account_id, &schedule);
+        }
+        self.transfer_reward_confirmed(&account_id, released, None);
+
+        env::log_str(&format!("{} claimed {} of vested rewards", account_id, released));
+        U128(released)
+    }
+
+    /// Retry a payout that previously failed to reach the caller
+    pub fn claim_failed_transfer(&mut self) -> U128 {
+        self.assert_not_paused();
+        let account_id = env::predecessor_account_id();
+        let amount = self.failed_transfers.remove(&account_id).unwrap_or(0);
+        require!(amount > 0, "No failed transfers to claim");
+
+        self.transfer_reward_confirmed(&account_id, amount, None);
+        U128(amount)
+    }
+    
+    /// Calculate weighted performance score for reward distribution
+    fn calculate_weighted_performance_score(&self, solver: &OracleSolver) -> f64 {
+        let base_score = solver.reputation_score;
+        let activity_bonus = (solver.total_evaluations as f64).ln().max(0.0) * 0.1;
+        let challenge_defense_bonus = if solver.performance_metrics.total_challenges_received > 0 {
+            (solver.performance_metrics.challenges_successfully_defended as f64 / 
+             solver.performance_metrics.total_challenges_received as f64) * 0.2
+        } else {
+            0.1 // Small bonus for no challenges (implies good work)
+        };
+        
+        let speed_bonus = if solver.performance_metrics.average_execution_time > 0.0 {
+            self.compute_speed_bonus(U64(solver.performance_metrics.average_execution_time as u64))
+        } else {
+            0.0
+        };
+        
+        let score = (base_score + activity_bonus + challenge_defense_bonus + speed_bonus)
+            * self.current_uptime_score(solver);
+        Self::finite_or(score, 0.0)
+    }
+
+    /// When the solver last sent a heartbeat, falling back to its last activity for solvers
+    /// that have never sent one
+    fn last_heartbeat(&self, solver: &OracleSolver) -> u64 {
+        self.solver_heartbeats
+            .get(&solver.solver_id)
+            .unwrap_or(solver.performance_metrics.last_active_timestamp)
+            .0
+    }
+
+    /// `uptime_score` with the decay for heartbeat intervals missed since the last heartbeat
+    /// applied, so a solver that has gone quiet loses weight before its next call
+    fn current_uptime_score(&self, solver: &OracleSolver) -> f64 {
+        let intervals = env::block_timestamp().saturating_sub(self.last_heartbeat(solver)) / HEARTBEAT_INTERVAL;
+        let missed = intervals.saturating_sub(1) as f64;
+        let keep = 1.0 - 1.0 / UPTIME_WINDOW_INTERVALS;
+        Self::finite_or(solver.performance_metrics.uptime_score * keep.powf(missed), 0.0)
+    }
+    
+    /// Batch process multiple operations for gas efficiency (owner only). Operations left
+    /// after expiring intents go to a step of the automatic cleanup sweep.
+    pub fn batch_process_expired_and_cleanup(&mut self, max_operations: u32) -> (u32, u32) {
+        self.assert_owner();
+        let expired_count = self.process_expired_intents(Some(max_operations));
+
+        if expired_count < max_operations {
+            let cleanup_count = self.auto_cleanup_step(max_operations - expired_count);
+            (expired_count, cleanup_count)
+        } else {
+            (expired_count, 0)
+        }
+    }
+
+    /// Network-wide statistics in a single O(1) call
+    pub fn get_network_stats(&self) -> NetworkStats {
+        let intents_by_status = [
+            IntentStatus::Pending,
+            IntentStatus::InProgress,
+            IntentStatus::Completed,
+            IntentStatus::Disputed,
+            IntentStatus::Settled,
+            IntentStatus::Expired,
+            IntentStatus::Cancelled,
+        ]
+        .into_iter()
+        .map(|status| {
+            let count = self.intent_status_counts.get(&status).unwrap_or(0);
+            (status, count)
+        })
+        .collect();
+
+        let evaluations_by_status = [
+            EvaluationStatus::Submitted,
+            EvaluationStatus::Verified,
+            EvaluationStatus::Challenged,
+            EvaluationStatus::Refuted,
+            EvaluationStatus::Confirmed,
+        ]
+        .into_iter()
+        .map(|status| {
+            let count = self.evaluation_status_counts.get(&status).unwrap_or(0);
+            (status, count)
+        })
+        .collect();
+
+        let solver_count = self.solver_ids.len();
+        let average_solver_reputation = if solver_count > 0 {
+            self.reputation_sum / solver_count as f64
+        } else {
+            0.0
+        };
+
+        NetworkStats {
+            intents_by_status,
+            evaluations_by_status,
+            active_solvers: self.active_solver_count,
+            total_value_staked: U128(self.total_escrowed),
+            average_solver_reputation,
+            total_rewards_distributed: U128(self.total_rewards_distributed),
+        }
+    }
+
+    /// Get storage usage statistics
+    pub fn get_storage_stats(&self) -> (u64, u64, u64, u64) {
+        (
+            self.intents.len(),
+            self.evaluations.len(), 
+            self.challenges.len(),
+            0 // users count not available with LookupMap
+        )
+    }
+
+    /// Process expired intents and return stakes. Walks the deadline index from the earliest
+    /// entry, touching at most `max_operations` entries (default `MAX_PAGE_LIMIT`).
+    pub fn process_expired_intents(&mut self, max_operations: Option<u32>) -> u32 {
+        self.assert_not_paused();
+        let current_time = env::block_timestamp();
+        let max_operations = max_operations.map_or(MAX_PAGE_LIMIT as usize, |max| max as usize);
+        let mut expired_count = 0;
+        
+        // Find index entries whose deadline has passed
+        let due_entries: Vec<(u64, String)> = self.pending_deadlines
+            .iter()
+            .take_while(|((deadline, _), _)| *deadline < current_time)
+            .take(max_operations)
+            .map(|(key, _)| key)
+            .collect();
+        
+        // Process expired intents; entries for intents that were extended, claimed or purged are dropped
+        for (deadline, intent_id) in due_entries {
+            self.pending_deadlines.remove(&(deadline, intent_id.clone()));
+            if let Some(mut intent) = self.intents.get(&intent_id) {
+                if intent.status != IntentStatus::Pending || intent.deadline.0 >= current_time {
+                    continue;
+                }
+                self.set_intent_status(&mut intent, IntentStatus::Expired);
+                self.intents.insert(&intent_id, &intent);
+                self.forget_intent_links(&intent_id);
+                
+                // Return stake to initiator
+                self.transfer_reward(&intent.initiator, intent.stake);
+                expired_count += 1;
+                
+                env::log_str(&format!("Intent {} expired, stake returned to {}", intent_id, intent.initiator));
+            }
+        }
+        
+        expired_count
+    }
+
+    /// Remove settled intents, challenges and evaluations older than the retention period,
+    /// examining at most `max_operations` records per call. The position is kept between
+    /// calls, so calling until this returns `false` sweeps every record once.
+    pub fn cleanup_old_data_resumable(&mut self, retention_days: u64, max_operations: u32) -> bool {
+        self.assert_owner();
+        require!(max_operations > 0, "max_operations must be positive");
+
+        let (cursor, cleaned_count, more_work) =
+            self.internal_cleanup_old_data(self.cleanup_cursor, retention_days, max_operations);
+        self.cleanup_cursor = cursor;
+        env::log_str(&format!(
+            "Cleaned up {} old records, {}",
+            cleaned_count,
+            if more_work { "more remain" } else { "sweep complete" }
+        ));
+        more_work
+    }
+
+    /// One bounded step of a cleanup sweep from `cursor`; returns where the sweep got to, the
+    /// records removed and whether it has further to go. Removing from an `UnorderedMap` swaps
+    /// its last entry into the freed slot, so the cursor only advances past entries that are kept.
+    fn internal_cleanup_old_data(
+        &mut self,
+        mut cursor: CleanupCursor,
+        retention_days: u64,
+        max_operations: u32,
+    ) -> (CleanupCursor, u32, bool) {
+        let retention_period = retention_days * 24 * 60 * 60 * 1_000_000_000; // Convert days to nanoseconds
+        let cutoff_time = env::block_timestamp().saturating_sub(retention_period);
+        let mut cleaned_count = 0;
+        let mut operations = 0;
+
+        while operations < max_operations {
+            operations += 1;
+            let removed = match cursor.phase {
+                CleanupPhase::Intents => {
+                    let Some(intent_id) = self.intents.keys_as_vector().get(cursor.index) else {
+                        cursor = CleanupCursor { phase: CleanupPhase::Challenges, index: 0 };
+                        continue;
+                    };
+                    let intent = self.intents.get(&intent_id).expect("Intent not found");
+                    let expired = intent.created_at.0 < cutoff_time &&
+                        matches!(
+                            intent.status,
+                            IntentStatus::Completed | IntentStatus::Settled | IntentStatus::Expired | IntentStatus::Cancelled
+                        );
+                    if expired {
+                        self.intents.remove(&intent_id);
+                        self.adjust_intent_status_count(&intent.status, false);
+                        self.quotes.remove(&intent_id);
+                        if let Some(mut history) = self.intent_history.remove(&intent_id) {
+                            history.clear();
+                        }
+                        self.forget_intent_links(&intent_id);
+                        self.intent_evaluation_counts.remove(&intent_id);
+                    }
+                    expired
+                },
+                CleanupPhase::Challenges => {
+                    let Some(challenge_id) = self.challenges.keys_as_vector().get(cursor.index) else {
+                        cursor = CleanupCursor { phase: CleanupPhase::Evaluations, index: 0 };
+                        continue;
+                    };
+                    let challenge = self.challenges.get(&challenge_id).expect("Challenge not found");
+                    let expired = challenge.submitted_at.0 < cutoff_time &&
+                        matches!(
+                            challenge.status,
+                            ChallengeStatus::Successful | ChallengeStatus::Failed | ChallengeStatus::Withdrawn
+                        );
+                    if expired {
+                        self.challenges.remove(&challenge_id);
+                        if let Some(evaluation) = self.evaluations.get(&challenge.evaluation_id) {
+                            Self::remove_indexed_id(&mut self.solver_challenges, &evaluation.solver, &challenge_id);
+                        }
+                    }
+                    expired
+                },
+                CleanupPhase::Evaluations => {
+                    let Some(evaluation_id) = self.evaluations.keys_as_vector().get(cursor.index) else {
+                        // Sweep finished; the next call starts over
+                        return (CleanupCursor::default(), cleaned_count, false);
+                    };
+                    let evaluation = self.evaluations.get(&evaluation_id).expect("Evaluation not found");
+                    let expired = evaluation.submitted_at.0 < cutoff_time &&
+                        matches!(evaluation.status, EvaluationStatus::Confirmed | EvaluationStatus::Refuted);
+                    if expired {
+                        self.evaluations.remove(&evaluation_id);
+                        self.adjust_evaluation_status_count(&evaluation.status, false);
+                        Self::remove_indexed_id(&mut self.solver_evaluations, &evaluation.solver, &evaluation_id);
+                        if let Some(mut challenge_ids) = self.evaluation_challenges.remove(&evaluation_id) {
+                            challenge_ids.clear();
+                        }
+                    }
+                    expired
+                },
+            };
+
+            if removed {
+                cleaned_count += 1;
+            } else {
+                cursor.index += 1;
+            }
+        }
+
+        (cursor, cleaned_count, true)
+    }
+
+    /// One step of the sweep behind `auto_cleanup`, which keeps its own cursor so it never
+    /// resumes an owner sweep running with a different retention period
+    fn auto_cleanup_step(&mut self, max_operations: u32) -> u32 {
+        let (cursor, cleaned_count, _) =
+            self.internal_cleanup_old_data(self.auto_cleanup_cursor, AUTO_CLEANUP_RETENTION_DAYS, max_operations);
+        self.auto_cleanup_cursor = cursor;
+        cleaned_count
+    }
+
+    /// Automatic cleanup that can be called by anyone (gas-efficient)
+    pub fn auto_cleanup(&mut self) -> u32 {
+        let expired_count = self.process_expired_intents(None);
+        
+        // Only perform expensive cleanup operations occasionally
+        if self.intent_counter % 100 == 0 {
+            // Clean data older than 30 days, one bounded step of the sweep at a time
+            expired_count + self.auto_cleanup_step(MAX_PAGE_LIMIT as u32)
+        } else {
+            expired_count
+        }
+    }
+
+    /// Add the attached deposit to the pool that funds reward bonuses
+    #[payable]
+    pub fn fund_bonus_pool(&mut self) {
+        self.assert_owner();
+        let amount = self.native_stake_deposit();
+        self.internal_fund_bonus_pool(amount);
+    }
+
+    fn internal_fund_bonus_pool(&mut self, amount: Balance) {
+        require!(amount > 0, "Attach a non-zero deposit to fund the bonus pool");
+
+        self.bonus_pool += amount;
+        self.total_escrowed += amount;
+
+        env::log_str(&format!("Bonus pool funded with {}, pool now {}", amount, self.bonus_pool));
+    }
+
+    /// Propose a new owner; control passes only once they call `accept_ownership`
+    pub fn propose_new_owner(&mut self, new_owner: AccountId) {
+        self.assert_owner();
+        require!(new_owner != self.owner, "Account is already the owner");
+
+        self.pending_owner = Some(new_owner.clone());
+
+        env::log_str(&format!("Ownership transfer proposed from {} to {}", self.owner, new_owner));
+    }
+
+    /// Complete an ownership transfer (pending owner only)
+    pub fn accept_ownership(&mut self) {
+        let caller = env::predecessor_account_id();
+        require!(
+            self.pending_owner.as_ref() == Some(&caller),
+            "Only the pending owner can accept ownership"
+        );
+
+        let previous_owner = std::mem::replace(&mut self.owner, caller.clone());
+        self.pending_owner = None;
+
+        env::log_str(&format!("Ownership transferred from {} to {}", previous_owner, caller));
+    }
+
+    /// Withdraw a pending ownership proposal
+    pub fn cancel_ownership_transfer(&mut self) {
+        self.assert_owner();
+        let pending_owner = self.pending_owner.take()
+            .expect("No ownership transfer pending");
+
+        env::log_str(&format!("Ownership transfer to {} cancelled", pending_owner));
+    }
+
+    /// Halt or resume all user-facing state changes; views and owner configuration stay available
+    pub fn set_paused(&mut self, paused: bool) {
+        self.assert_owner();
+        require!(
+            paused || self.legacy_record_migration.is_none(),
+            "Finish migrate_legacy_records before unpausing"
+        );
+
+        self.paused = paused;
+
+        env::log_str(&format!(
+            "Contract {} by {}",
+            if paused { "paused" } else { "unpaused" },
+            env::predecessor_account_id()
+        ));
+    }
+
+    /// Require solvers to list a categorized intent's category among their specializations
+    pub fn set_require_specialization_match(&mut self, required: bool) {
+        self.assert_owner();
+
+        self.require_specialization_match = required;
+
+        env::log_str(&format!("Specialization match requirement set to {}", required));
+    }
+
+    /// Set the range (in nanoseconds) per-intent challenge periods are clamped to
+    pub fn set_challenge_period_bounds(&mut self, min_period: U64, max_period: U64) {
+        self.assert_owner();
+        require!(min_period.0 > 0, "Minimum challenge period must be positive");
+        require!(min_period.0 <= max_period.0, "Minimum challenge period exceeds maximum");
+
+        self.min_challenge_period = min_period;
+        self.max_challenge_period = max_period;
+
+        env::log_str(&format!(
+            "Challenge period bounds set to {}-{} ns",
+            min_period.0, max_period.0
+        ));
+    }
+
+    /// Set the multiple of the evaluation stake a challenger must put up
+    pub fn set_challenge_stake_multiplier(&mut self, multiplier: f64) {
+        self.assert_owner();
+        require!(multiplier.is_finite() && multiplier >= 1.0, "Challenge stake multiplier must be a finite number of at least 1");
+
+        self.challenge_stake_multiplier = multiplier;
+
+        env::log_str(&format!("Challenge stake multiplier set to {}", multiplier));
+    }
+
+    /// Set the share of registration stake (in basis points) slashed when an evaluator loses a dispute
+    pub fn set_slash_fraction_bps(&mut self, slash_fraction_bps: u16) {
+        self.assert_owner();
+        require!(slash_fraction_bps as u128 <= BPS_DENOMINATOR, "Slash fraction cannot exceed 10000 bps");
+
+        self.slash_fraction_bps = slash_fraction_bps;
+
+        env::log_str(&format!("Slash fraction set to {} bps", slash_fraction_bps));
+    }
+
+    /// Set how long (nanoseconds) an assigned solver may hold an intent without submitting
+    /// before `reclaim_stalled_intent` can release it
+    pub fn set_stall_timeout(&mut self, stall_timeout: U64) {
+        self.assert_owner();
+        require!(stall_timeout.0 > 0, "Stall timeout must be positive");
+
+        self.stall_timeout = stall_timeout;
+
+        env::log_str(&format!("Stall timeout set to {} ns", stall_timeout.0));
+    }
+
+    /// Set reward vesting: payouts above `threshold` are released in `tranches` equal parts over
+    /// `period` nanoseconds. A threshold of 0 pays every reward at once.
+    pub fn set_vesting_policy(&mut self, threshold: U128, period: U64, tranches: u32) {
+        self.assert_owner();
+        require!(
+            tranches > 0 && tranches <= MAX_VESTING_TRANCHES,
+            format!("Tranches must be between 1 and {}", MAX_VESTING_TRANCHES)
+        );
+
+        self.vesting_threshold = threshold.0;
+        self.vesting_period = period;
+        self.vesting_tranches = tranches;
+
+        env::log_str(&format!(
+            "Vesting set for payouts above {} over {} ns in {} tranches",
+            threshold.0, period.0, tranches
+        ));
+    }
+
+    /// Set who may file challenges: solvers need `min_reputation` (on top of Silver tier), and
+    /// other users need verification at `min_verification_level` or above. A `None` level
+    /// keeps challenges to solvers only.
+    pub fn set_challenger_requirements(&mut self, min_reputation: f64, min_verification_level: Option<u8>) {
+        self.assert_owner();
+        require!((0.0..=1.0).contains(&min_reputation), "Minimum reputation must be between 0 and 1");
+        require!(
+            min_verification_level.is_none_or(|level| (1..=5).contains(&level)),
+            "Verification level must be 1-5"
+        );
+
+        self.min_challenger_reputation = min_reputation;
+        self.min_challenger_verification_level = min_verification_level;
+
+        env::log_str(&format!(
+            "Challengers need reputation {} or verification level {:?}",
+            min_reputation, min_verification_level
+        ));
+    }
+
+    /// Lengthen how long (nanoseconds) queued admin actions wait before they can be executed.
+    /// Shortening it has to wait out the current delay, via a queued `SetAdminActionDelay`.
+    pub fn set_admin_action_delay(&mut self, admin_action_delay: U64) {
+        self.assert_owner();
+        require!(
+            admin_action_delay.0 >= self.admin_action_delay.0,
+            "Shortening the admin action delay must be queued with queue_admin_action"
+        );
+        self.internal_set_admin_action_delay(admin_action_delay);
+    }
+
+    fn internal_set_admin_action_delay(&mut self, admin_action_delay: U64) {
+        require!(
+            admin_action_delay.0 >= MIN_ADMIN_ACTION_DELAY,
+            "Admin action delay must be at least one hour"
+        );
+
+        self.admin_action_delay = admin_action_delay;
+
+        env::log_str(&format!("Admin action delay set to {} ns", admin_action_delay.0));
+    }
+
+    /// Set how long (nanoseconds) after a challenge is submitted the owner has to settle the
+    /// dispute before anyone can call `force_resolve_stale_dispute`
+    pub fn set_dispute_settlement_deadline(&mut self, dispute_settlement_deadline: U64) {
+        self.assert_owner();
+        require!(dispute_settlement_deadline.0 > 0, "Dispute settlement deadline must be positive");
+
+        self.dispute_settlement_deadline = dispute_settlement_deadline;
+
+        env::log_str(&format!("Dispute settlement deadline set to {} ns", dispute_settlement_deadline.0));
+    }
+
+    /// Set how long (nanoseconds) an intent must be past its deadline before
+    /// `force_expire_intent` can close it
+    pub fn set_force_expire_timeout(&mut self, force_expire_timeout: U64) {
+        self.assert_owner();
+
+        self.force_expire_timeout = force_expire_timeout;
+
+        env::log_str(&format!("Force-expiry timeout set to {} ns", force_expire_timeout.0));
+    }
+
+    /// Set the share (bps) of the losing stake, after fees, that a dispute winner takes; the rest
+    /// is returned to the loser. 10000 is winner-take-all.
+    pub fn set_dispute_winner_share(&mut self, dispute_winner_bps: u16) {
+        self.assert_owner();
+        require!(dispute_winner_bps as u128 <= BPS_DENOMINATOR, "Winner share cannot exceed 10000 bps");
+
+        self.dispute_winner_bps = dispute_winner_bps;
+
+        env::log_str(&format!("Dispute winner share set to {} bps", dispute_winner_bps));
+    }
+
+    /// Set the range of deadlines (in minutes) intents may be created with; the maximum also
+    /// caps how far extensions can push a deadline past creation
+    pub fn set_deadline_bounds(&mut self, min_deadline_minutes: u64, max_deadline_minutes: u64) {
+        self.assert_owner();
+        require!(max_deadline_minutes > 0, "Maximum deadline must be positive");
+        require!(
+            min_deadline_minutes <= max_deadline_minutes,
+            "Minimum deadline cannot exceed the maximum"
+        );
+
+        self.min_deadline_minutes = min_deadline_minutes;
+        self.max_deadline_minutes = max_deadline_minutes;
+
+        env::log_str(&format!(
+            "Deadline bounds set to {}-{} minutes",
+            min_deadline_minutes, max_deadline_minutes
+        ));
+    }
+
+    /// Set the share of stake (in basis points) a challenger forfeits by withdrawing a challenge
+    pub fn set_challenge_withdrawal_penalty_bps(&mut self, penalty_bps: u16) {
+        self.assert_owner();
+        require!(penalty_bps as u128 <= BPS_DENOMINATOR, "Withdrawal penalty cannot exceed 10000 bps");
+
+        self.challenge_withdrawal_penalty_bps = penalty_bps;
+
+        env::log_str(&format!("Challenge withdrawal penalty set to {} bps", penalty_bps));
+    }
+
+    /// Allow or block a source domain (owner/admin only); the entry also covers its subdomains
+    pub fn set_domain_allowed(&mut self, domain: String, allowed: bool) {
+        self.assert_admin_or_owner();
+        let domain = domain.to_lowercase();
+        require!(
+            Self::url_host(&format!("https://{}", domain)).as_deref() == Some(domain.as_str()),
+            "Invalid domain"
+        );
+
+        self.allowed_domains.insert(&domain, &allowed);
+
+        env::log_str(&format!("Domain {} {}", domain, if allowed { "allowed" } else { "blocked" }));
+    }
+
+    /// Drop a domain from the registry (owner/admin only)
+    pub fn remove_domain(&mut self, domain: String) {
+        self.assert_admin_or_owner();
+        let domain = domain.to_lowercase();
+        require!(self.allowed_domains.remove(&domain).is_some(), "Domain is not in the registry");
+
+        env::log_str(&format!("Domain {} removed from registry", domain));
+    }
+
+    /// Require evaluations with several sources to draw on several hosts, with no host
+    /// supplying more than `max_share_bps` of the sources
+    pub fn set_source_diversity(&mut self, required: bool, max_share_bps: u16) {
+        self.assert_owner();
+        require!(
+            max_share_bps > 0 && max_share_bps as u128 <= BPS_DENOMINATOR,
+            "Maximum share must be between 1 and 10000 bps"
+        );
+
+        self.require_source_diversity = required;
+        self.max_source_domain_share_bps = max_share_bps;
+
+        env::log_str(&format!(
+            "Source diversity requirement set to {} with max share {} bps",
+            required, max_share_bps
+        ));
+    }
+
+    /// Set the speed bonus curve: `max_bonus_bps` is paid for instant execution and the bonus
+    /// falls linearly to zero at `target_execution_ms`
+    pub fn set_speed_bonus(&mut self, max_bonus_bps: u16, target_execution_ms: U64) {
+        self.assert_owner();
+        require!(max_bonus_bps as u128 <= BPS_DENOMINATOR, "Speed bonus cannot exceed 10000 bps");
+        require!(target_execution_ms.0 > 0, "Target execution time must be positive");
+
+        self.speed_bonus_cap_bps = max_bonus_bps;
+        self.speed_bonus_target_ms = target_execution_ms.0;
+
+        env::log_str(&format!(
+            "Speed bonus set to at most {} bps, reaching zero at {} ms",
+            max_bonus_bps, target_execution_ms.0
+        ));
+    }
+
+    /// Set how many evaluations a single intent accepts over its lifetime, counting every
+    /// quorum evaluation and every answer after a relist
+    pub fn set_max_evaluations_per_intent(&mut self, max_evaluations: u32) {
+        self.assert_owner();
+        require!(
+            (1..=MAX_QUORUM_EVALUATIONS as u32).contains(&max_evaluations),
+            "Evaluation limit must be between 1 and 25"
+        );
+
+        self.max_evaluations_per_intent = max_evaluations;
+
+        env::log_str(&format!("Max evaluations per intent set to {}", max_evaluations));
+    }
+
+    /// Set the corroboration bonus: evaluations whose sources were mostly already cited by at
+    /// least `min_corroborations` confirmed evaluations earn `bonus_bps` more, from the bonus pool
+    pub fn set_corroboration_bonus(&mut self, bonus_bps: u16, min_corroborations: u64) {
+        self.assert_owner();
+        require!(bonus_bps <= MAX_CORROBORATION_BONUS_BPS, "Corroboration bonus cannot exceed 1000 bps");
+        require!(min_corroborations > 0, "Minimum corroborations must be positive");
+
+        self.corroboration_bonus_bps = bonus_bps;
+        self.corroboration_min_count = min_corroborations;
+
+        env::log_str(&format!(
+            "Corroboration bonus set to {} bps for sources cited by {} confirmed evaluations",
+            bonus_bps, min_corroborations
+        ));
+    }
+
+    /// Require every evaluation and challenge source to come from an allowed domain
+    pub fn set_enforce_domain_allowlist(&mut self, enforce: bool) {
+        self.assert_owner();
+
+        self.enforce_domain_allowlist = enforce;
+
+        env::log_str(&format!("Domain allowlist enforcement set to {}", enforce));
+    }
+
+    /// Seed the reliability score (0-1) used by `auto_settle_dispute` for a source domain
+    pub fn set_domain_reliability(&mut self, domain: String, score: f64) {
+        self.assert_owner();
+        require!((0.0..=1.0).contains(&score), "Reliability score must be between 0 and 1");
+
+        let domain = domain.to_lowercase();
+        self.domain_reliability.insert(&domain, &score);
+
+        env::log_str(&format!("Reliability of {} set to {}", domain, score));
+    }
+
+    /// Set the NEP-141 token used for stakes and rewards, or `None` to use native NEAR. Only
+    /// possible while nothing is escrowed, so every stake is repaid in the asset it came in.
+    pub fn set_stake_token(&mut self, stake_token: Option<AccountId>) {
+        self.assert_owner();
+        require!(
+            self.total_escrowed == 0,
+            "Stake token can only change while nothing is escrowed"
+        );
+
+        self.stake_token = stake_token.clone();
+
+        match stake_token {
+            Some(token) => env::log_str(&format!("Stake token set to {}", token)),
+            None => env::log_str("Stake token cleared, using native NEAR"),
+        }
+    }
+
+    /// Set the non-refundable fee charged on top of the stake when a solver registers
+    pub fn set_registration_fee(&mut self, registration_fee: U128) {
+        self.assert_owner();
+
+        self.registration_fee = registration_fee.0;
+
+        env::log_str(&format!("Registration fee set to {}", registration_fee.0));
+    }
+
+    /// Set the reward paid to whoever finalizes an evaluation, capped at the intent reward left
+    /// after the protocol fee
+    pub fn set_keeper_reward(&mut self, keeper_reward: U128) {
+        self.assert_owner();
+
+        self.keeper_reward = keeper_reward.0;
+
+        env::log_str(&format!("Keeper reward set to {}", keeper_reward.0));
+    }
+
+    /// Set the bonus paid from the treasury to a referrer when their referral first succeeds
+    pub fn set_referral_bonus(&mut self, referral_bonus: U128) {
+        self.assert_owner();
+
+        self.referral_bonus = referral_bonus.0;
+
+        env::log_str(&format!("Referral bonus set to {}", referral_bonus.0));
+    }
+
+    /// Set the protocol's cut of evaluation rewards and dispute winnings, in basis points
+    pub fn set_protocol_fee_bps(&mut self, protocol_fee_bps: u16) {
+        self.assert_owner();
+        require!(protocol_fee_bps as u128 <= BPS_DENOMINATOR, "Protocol fee cannot exceed 10000 bps");
+
+        self.protocol_fee_bps = protocol_fee_bps;
+
+        env::log_str(&format!("Protocol fee set to {} bps", protocol_fee_bps));
+    }
+
+    /// Pay collected registration and protocol fees out of the treasury to the owner
+    pub fn withdraw_treasury(&mut self, amount: U128) {
+        self.assert_owner();
+        require!(amount.0 > 0, "Amount must be positive");
+        require!(amount.0 <= self.protocol_treasury, "Amount exceeds treasury balance");
+
+        self.protocol_treasury -= amount.0;
+        let owner = self.owner.clone();
+        self.transfer_reward_confirmed(&owner, amount.0, None);
+
+        env::log_str(&format!("Withdrew {} from treasury, {} remaining", amount.0, self.protocol_treasury));
+    }
+
+    /// Set the reputation and evaluation-count requirements for the Silver, Gold and Platinum tiers
+    pub fn set_tier_thresholds(&mut self, tier_thresholds: TierThresholds) {
+        self.assert_owner();
+        let tiers = [tier_thresholds.silver, tier_thresholds.gold, tier_thresholds.platinum];
+        for requirement in &tiers {
+            require!(
+                (0.0..=1.0).contains(&requirement.min_reputation),
+                "Tier reputation must be between 0 and 1"
+            );
+        }
+        require!(
+            tiers.windows(2).all(|pair| {
+                pair[0].min_reputation <= pair[1].min_reputation
+                    && pair[0].min_evaluations <= pair[1].min_evaluations
+            }),
+            "Higher tiers cannot have lower requirements"
+        );
+
+        self.tier_thresholds = tier_thresholds;
+
+        env::log_str("Reputation tier thresholds updated");
+    }
+
+    /// Set the base cooldown (nanoseconds) after a lost dispute; it is multiplied by the
+    /// intent's reward in units of min stake, up to 10x
+    pub fn set_dispute_cooldown(&mut self, dispute_cooldown: U64) {
+        self.assert_owner();
+
+        self.dispute_cooldown = dispute_cooldown;
+
+        env::log_str(&format!("Dispute cooldown set to {} ns", dispute_cooldown.0));
+    }
+
+    /// Set the prior blended into every reputation score: `prior_weight` virtual results
+    /// averaging `prior_mean`. Scores pick up the new prior the next time they are updated.
+    pub fn set_reputation_prior(&mut self, prior_mean: f64, prior_weight: f64) {
+        self.assert_owner();
+        require!((0.0..=1.0).contains(&prior_mean), "Prior mean must be between 0 and 1");
+        require!(prior_weight.is_finite() && prior_weight >= 0.0, "Prior weight must be a non-negative number");
+
+        self.reputation_prior_mean = prior_mean;
+        self.reputation_prior_weight = prior_weight;
+
+        env::log_str(&format!(
+            "Reputation prior set to mean {} with weight {}",
+            prior_mean, prior_weight
+        ));
+    }
+
+    /// Set the stake and minimum window age (nanoseconds) a solver needs to reset their reputation window
+    pub fn set_reputation_reset_policy(&mut self, min_stake: U128, cooldown: U64) {
+        self.assert_owner();
+
+        self.reputation_reset_min_stake = min_stake.0;
+        self.reputation_reset_cooldown = cooldown;
+
+        env::log_str(&format!(
+            "Reputation reset policy set: min stake {}, cooldown {} ns",
+            min_stake.0, cooldown.0
+        ));
+    }
+
+    /// Set how far apart two numeric answers may be and still count as agreeing
+    pub fn set_answer_tolerance(&mut self, answer_tolerance: f64) {
+        self.assert_owner();
+        require!(answer_tolerance.is_finite() && answer_tolerance >= 0.0, "Tolerance must be a non-negative number");
+
+        self.answer_tolerance = answer_tolerance;
+
+        env::log_str(&format!("Answer tolerance set to {}", answer_tolerance));
+    }
+
+    /// Set the largest reward an intent may carry; larger deposits are capped and refunded
+    pub fn set_max_reward(&mut self, max_reward: U128) {
+        self.assert_owner();
+        require!(max_reward.0 >= self.min_stake, "Maximum reward cannot be below the minimum stake");
+
+        self.max_reward = max_reward.0;
+
+        env::log_str(&format!("Maximum reward set to {}", max_reward.0));
+    }
+
+    /// Set how many settled evaluations a solver needs before sharing in performance rewards
+    pub fn set_min_evaluations_for_pool(&mut self, min_evaluations: u64) {
+        self.assert_owner();
+
+        self.min_evaluations_for_pool = min_evaluations;
+
+        env::log_str(&format!("Minimum evaluations for performance pool set to {}", min_evaluations));
+    }
+
+    /// Set the minimum stake required for a specific intent type (overrides the global minimum)
+    pub fn set_min_stake_for_type(&mut self, intent_type: IntentType, amount: U128) {
+        self.assert_owner();
+
+        self.min_stake_by_type.insert(&intent_type, &amount.0);
+
+        env::log_str(&format!("Minimum stake for {:?} set to {}", intent_type, amount.0));
+    }
+
+    /// Set how long (in nanoseconds) after submission a solver may amend an evaluation
+    pub fn set_amendment_window(&mut self, amendment_window: U64) {
+        self.assert_owner();
+
+        self.amendment_window = amendment_window;
+
+        env::log_str(&format!("Amendment window set to {} ns", amendment_window.0));
+    }
+
+    /// Set how long (in nanoseconds) a commitment must wait before it can be revealed
+    pub fn set_reveal_delay(&mut self, reveal_delay: U64) {
+        self.assert_owner();
+
+        self.reveal_delay = reveal_delay;
+
+        env::log_str(&format!("Reveal delay set to {} ns", reveal_delay.0));
+    }
+
+    /// Set how long (in nanoseconds) a new intent waits before solvers may claim, quote on or
+    /// answer it, so every solver sees it before anyone can take it
+    pub fn set_intent_reveal_delay(&mut self, intent_reveal_delay: U64) {
+        self.assert_owner();
+
+        self.intent_reveal_delay = intent_reveal_delay;
+
+        env::log_str(&format!("Intent reveal delay set to {} ns", intent_reveal_delay.0));
+    }
+
+    /// Cap how many evaluations a solver may have Submitted or Challenged at once
+    pub fn set_max_active_evaluations_per_solver(&mut self, max_active_evaluations: u32) {
+        self.assert_owner();
+        require!(max_active_evaluations > 0, "Active evaluation cap must be positive");
+
+        self.max_active_evaluations_per_solver = max_active_evaluations;
+
+        env::log_str(&format!("Max active evaluations per solver set to {}", max_active_evaluations));
+    }
+
+    /// Set how long (in nanoseconds) the losing party has to appeal a settled dispute
+    pub fn set_appeal_period(&mut self, appeal_period: U64) {
+        self.assert_owner();
+
+        self.appeal_period = appeal_period;
+
+        env::log_str(&format!("Appeal period set to {} ns", appeal_period.0));
+    }
+
+    /// Set how long (in nanoseconds) it takes a past result to lose half its reputation weight
+    pub fn set_reputation_half_life(&mut self, half_life: U64) {
+        self.assert_owner();
+        require!(half_life.0 > 0, "Half-life must be positive");
+
+        self.reputation_half_life = half_life;
+
+        env::log_str(&format!("Reputation half-life set to {} ns", half_life.0));
+    }
+
+    /// View methods
+    pub fn get_intent(&self, intent_id: String) -> Option<OracleIntent> {
+        self.intents.get(&intent_id)
+    }
+
+    pub fn get_intent_requirements(&self, intent_id: String) -> Option<IntentRequirements> {
+        self.intents.get(&intent_id).map(|intent| IntentRequirements {
+            required_sources: intent.required_sources,
+            confidence_threshold: intent.confidence_threshold,
+        })
+    }
+
+    pub fn get_state_version(&self) -> u32 {
+        self.state_version
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn get_pending_owner(&self) -> Option<AccountId> {
+        self.pending_owner.clone()
+    }
+
+    pub fn get_intent_templates(&self, owner: AccountId, from_index: u64, limit: u64) -> Vec<IntentTemplate> {
+        self.intent_templates
+            .values()
+            .filter(|template| template.owner == owner)
+            .skip(from_index as usize)
+            .take(limit.min(MAX_PAGE_LIMIT) as usize)
+            .collect()
+    }
+
+    /// Whether `submit_challenge` would currently accept a challenge against the evaluation
+    pub fn is_evaluation_challengeable(&self, evaluation_id: String) -> bool {
+        self.evaluations.get(&evaluation_id).is_some_and(|evaluation| {
+            evaluation.status == EvaluationStatus::Submitted
+                && env::block_timestamp() <= self.challenge_deadline(&evaluation)
+        })
+    }
+
+    /// (opens, closes) timestamps of the evaluation's challenge window; an amendment reopens it
+    pub fn get_challenge_window(&self, evaluation_id: String) -> Option<(U64, U64)> {
+        self.evaluations.get(&evaluation_id).map(|evaluation| {
+            let opens = evaluation.amended_at.unwrap_or(evaluation.submitted_at);
+            (opens, U64(self.challenge_deadline(&evaluation)))
+        })
+    }
+
+    /// Look up several intents at once; results line up with `intent_ids`, None where missing
+    pub fn get_intents_by_ids(&self, intent_ids: Vec<String>) -> Vec<Option<OracleIntent>> {
+        require!(intent_ids.len() as u64 <= MAX_PAGE_LIMIT, "Too many IDs requested");
+        intent_ids.iter().map(|intent_id| self.intents.get(intent_id)).collect()
+    }
+
+    /// Look up several evaluations at once; results line up with `evaluation_ids`, None where missing
+    pub fn get_evaluations_by_ids(&self, evaluation_ids: Vec<String>) -> Vec<Option<OracleEvaluation>> {
+        require!(evaluation_ids.len() as u64 <= MAX_PAGE_LIMIT, "Too many IDs requested");
+        evaluation_ids.iter().map(|evaluation_id| self.evaluations.get(evaluation_id)).collect()
+    }
+
+    pub fn get_evaluation(&self, evaluation_id: String) -> Option<OracleEvaluation> {
+        self.evaluations.get(&evaluation_id)
+    }
+
+    /// Evaluations submitted by `solver_id`, oldest first
+    pub fn get_evaluations_by_solver(&self, solver_id: AccountId, from_index: u64, limit: u64) -> Vec<OracleEvaluation> {
+        let Some(evaluation_ids) = self.solver_evaluations.get(&solver_id) else {
+            return vec![];
+        };
+
+        let end = from_index.saturating_add(limit.min(MAX_PAGE_LIMIT)).min(evaluation_ids.len());
+        (from_index..end)
+            .filter_map(|index| evaluation_ids.get(index))
+            .filter_map(|evaluation_id| self.evaluations.get(&evaluation_id))
+            .collect()
+    }
+
+    /// Challenges filed against `solver_id`'s evaluations, oldest first, with their current status
+    pub fn get_challenges_against_solver(
+        &self,
+        solver_id: AccountId,
+        from_index: u64,
+        limit: u64,
+    ) -> Vec<RefutationChallenge> {
+        let Some(challenge_ids) = self.solver_challenges.get(&solver_id) else {
+            return vec![];
+        };
+
+        let end = from_index.saturating_add(limit.min(MAX_PAGE_LIMIT)).min(challenge_ids.len());
+        (from_index..end)
+            .filter_map(|index| challenge_ids.get(index))
+            .filter_map(|challenge_id| self.challenges.get(&challenge_id))
+            .collect()
+    }
+
+    pub fn get_challenge(&self, challenge_id: String) -> Option<RefutationChallenge> {
+        self.challenges.get(&challenge_id)
+    }
+
+    /// Every challenge raised against an evaluation, in submission order
+    pub fn get_evaluation_challenges(&self, evaluation_id: String) -> Vec<RefutationChallenge> {
+        self.evaluation_challenges.get(&evaluation_id)
+            .map(|ids| ids.iter().filter_map(|challenge_id| self.challenges.get(&challenge_id)).collect())
+            .unwrap_or_default()
+    }
+
+    /// A challenge joined with the evaluation it disputes, or None if the challenge doesn't exist
+    pub fn get_dispute_details(&self, challenge_id: String) -> Option<DisputeDetails> {
+        let challenge = self.challenges.get(&challenge_id)?;
+        let evaluation = self.evaluations.get(&challenge.evaluation_id)?;
+        Some(DisputeDetails {
+            challenge_id: challenge.challenge_id,
+            evaluation_id: evaluation.evaluation_id,
+            intent_id: evaluation.intent_id,
+            question: evaluation.question,
+            solver: evaluation.solver,
+            answer: evaluation.answer,
+            answer_value: evaluation.answer_value,
+            confidence: evaluation.confidence,
+            sources: evaluation.sources,
+            evaluation_stake: U128(evaluation.stake),
+            evaluation_status: evaluation.status,
+            challenger: challenge.challenger,
+            counter_sources: challenge.counter_sources,
+            rebuttal_sources: challenge.rebuttal_sources,
+            challenge_stake: U128(challenge.stake),
+            challenge_status: challenge.status,
+            submitted_at: challenge.submitted_at,
+        })
+    }
+
+    /// Aggregate the confirmed evaluations behind `intent_ids` into a weighted consensus.
+    /// Each answer is weighted by `reputation_score * confidence`; intents without a confirmed
+    /// evaluation are skipped.
+    pub fn aggregate_evaluations(&self, intent_ids: Vec<String>, confidence_threshold: f64) -> AggregatedResult {
+        require!(intent_ids.len() as u64 <= MAX_PAGE_LIMIT, "Too many intents to aggregate");
+
+        let mut yes_weight = 0.0;
+        let mut no_weight = 0.0;
+        let mut total_source_count = 0;
+        let mut evaluations_counted = 0;
+
+        for intent_id in intent_ids {
+            let evaluation = match self.intents.get(&intent_id)
+                .and_then(|intent| intent.evaluation_hash)
+                .and_then(|evaluation_id| self.evaluations.get(&evaluation_id))
+            {
+                Some(evaluation) if evaluation.status == EvaluationStatus::Confirmed => evaluation,
+                _ => continue,
+            };
+
+            let reputation = self.solvers.get(&evaluation.solver)
+                .map(|solver| solver.reputation_score)
+                .unwrap_or(0.0);
+            let weight = reputation * evaluation.confidence;
+
+            if evaluation.answer {
+                yes_weight += weight;
+            } else {
+                no_weight += weight;
+            }
+            total_source_count += evaluation.sources.len() as u64;
+            evaluations_counted += 1;
+        }
+
+        let total_weight = yes_weight + no_weight;
+        let outcome = yes_weight > no_weight;
+        let combined_confidence = if total_weight > 0.0 {
+            yes_weight.max(no_weight) / total_weight
+        } else {
+            0.0
+        };
+
+        AggregatedResult {
+            outcome,
+            combined_confidence,
+            total_source_count,
+            evaluations_counted,
+            meets_threshold: evaluations_counted > 0 && combined_confidence >= confidence_threshold,
+        }
+    }
+
+    /// Escrow obligations alongside the contract's actual balance: `(total_escrowed, account_balance)`
+    pub fn get_escrow_health(&self) -> (U128, U128) {
+        (U128(self.total_escrowed), U128(env::account_balance().as_yoctonear()))
+    }
+
+    pub fn get_bonus_pool(&self) -> U128 {
+        U128(self.bonus_pool)
+    }
+
+    /// (minimum, maximum) reward accepted for a credibility intent at normal priority
+    pub fn get_reward_limits(&self) -> (U128, U128) {
+        (
+            U128(self.effective_min_stake(&IntentType::CredibilityEvaluation)),
+            U128(self.max_reward),
+        )
+    }
+
+    pub fn get_registration_fee(&self) -> U128 {
+        U128(self.registration_fee)
+    }
+
+    pub fn get_protocol_treasury(&self) -> U128 {
+        U128(self.protocol_treasury)
+    }
+
+    pub fn get_protocol_fee_bps(&self) -> u16 {
+        self.protocol_fee_bps
+    }
+
+    pub fn get_keeper_reward(&self) -> U128 {
+        U128(self.keeper_reward)
+    }
+
+    pub fn get_referral_stats(&self, account_id: AccountId) -> ReferralStats {
+        self.referral_stats.get(&account_id).unwrap_or_default()
+    }
+
+    pub fn get_solver_preferences(&self, solver_id: AccountId) -> Option<SolverPreferences> {
+        self.solver_preferences.get(&solver_id)
+    }
+
+    pub fn get_referrer(&self, account_id: AccountId) -> Option<AccountId> {
+        self.referrals.get(&account_id).map(|referral| referral.referrer)
+    }
+
+    pub fn get_referral_bonus(&self) -> U128 {
+        U128(self.referral_bonus)
+    }
+
+    pub fn is_blacklisted(&self, account_id: AccountId) -> bool {
+        self.blacklist.contains_key(&account_id)
+    }
+
+    pub fn get_blacklist_reason(&self, account_id: AccountId) -> Option<String> {
+        self.blacklist.get(&account_id)
+    }
+
+    pub fn get_cleanup_cursor(&self) -> CleanupCursor {
+        self.cleanup_cursor
+    }
+
+    pub fn get_stall_timeout(&self) -> U64 {
+        self.stall_timeout
+    }
+
+    pub fn get_dispute_winner_share(&self) -> u16 {
+        self.dispute_winner_bps
+    }
+
+    pub fn get_intent_reveal_delay(&self) -> U64 {
+        self.intent_reveal_delay
+    }
+
+    /// The canonical intent a duplicate waits on, if it is linked
+    pub fn get_canonical_intent(&self, intent_id: String) -> Option<String> {
+        self.duplicate_links.get(&intent_id)
+    }
+
+    /// Duplicates that resolve when this intent's evaluation is confirmed
+    pub fn get_linked_duplicates(&self, intent_id: String) -> Vec<String> {
+        self.linked_duplicates.get(&intent_id).unwrap_or_default()
+    }
+
+    /// When solvers may first take the intent, or None if it doesn't exist
+    pub fn get_intent_open_time(&self, intent_id: String) -> Option<U64> {
+        self.intents.get(&intent_id).map(|intent| U64(self.intent_open_time(&intent)))
+    }
+
+    /// Unreleased reward tranches for an account as (release time, amount), earliest first
+    pub fn get_vesting_schedule(&self, account_id: AccountId) -> Vec<(U64, U128)> {
+        self.vesting_schedule.get(&account_id)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(release_at, tranche)| (release_at, U128(tranche)))
+            .collect()
+    }
+
+    /// Vesting threshold, period and tranche count
+    pub fn get_vesting_policy(&self) -> (U128, U64, u32) {
+        (U128(self.vesting_threshold), self.vesting_period, self.vesting_tranches)
+    }
+
+    /// Minimum solver reputation and user verification level (None when users can't) to challenge
+    pub fn get_challenger_requirements(&self) -> (f64, Option<u8>) {
+        (self.min_challenger_reputation, self.min_challenger_verification_level)
+    }
+
+    pub fn get_admin_action_delay(&self) -> U64 {
+        self.admin_action_delay
+    }
+
+    pub fn get_admin_action(&self, action_id: u64) -> Option<QueuedAdminAction> {
+        self.admin_actions.get(&action_id)
+    }
+
+    /// Queued admin actions in storage order; running or cancelling one moves the newest into its slot
+    pub fn get_queued_admin_actions(&self, from_index: u64, limit: u64) -> Vec<QueuedAdminAction> {
+        self.admin_actions
+            .values()
+            .skip(from_index as usize)
+            .take(limit.min(MAX_PAGE_LIMIT) as usize)
+            .collect()
+    }
+
+    pub fn get_force_expire_timeout(&self) -> U64 {
+        self.force_expire_timeout
+    }
+
+    pub fn get_dispute_settlement_deadline(&self) -> U64 {
+        self.dispute_settlement_deadline
+    }
+
+    /// A solver's uptime score including the decay for heartbeats missed so far, and when it
+    /// last sent a heartbeat (or was last active, if it never has)
+    pub fn get_solver_uptime(&self, solver_id: AccountId) -> Option<(f64, U64)> {
+        self.solvers
+            .get(&solver_id)
+            .map(|solver| (self.current_uptime_score(&solver), U64(self.last_heartbeat(&solver))))
+    }
+
+    /// Shortest and longest deadline (in minutes) accepted for new intents
+    pub fn get_deadline_bounds(&self) -> (u64, u64) {
+        (self.min_deadline_minutes, self.max_deadline_minutes)
+    }
+
+    pub fn get_challenge_withdrawal_penalty_bps(&self) -> u16 {
+        self.challenge_withdrawal_penalty_bps
+    }
+
+    pub fn get_solver_active_evaluation_count(&self, solver_id: AccountId) -> u32 {
+        self.active_evaluation_counts.get(&solver_id).unwrap_or(0)
+    }
+
+    pub fn get_verifier_stats(&self, account_id: AccountId) -> VerifierStats {
+        let record = self.verifier_records.get(&account_id).unwrap_or_default();
+        let majority_agreement_rate = if record.votes_cast > 0 {
+            record.majority_votes as f64 / record.votes_cast as f64
+        } else {
+            0.0
+        };
+
+        VerifierStats {
+            stake: U128(self.verifier_stakes.get(&account_id).unwrap_or(0)),
+            votes_cast: record.votes_cast,
+            majority_votes: record.majority_votes,
+            majority_agreement_rate,
+        }
+    }
+
+    pub fn get_pending_withdrawal(&self, account_id: AccountId) -> U128 {
+        U128(self.pending_withdrawals.get(&account_id).unwrap_or(0))
+    }
+
+    pub fn get_failed_transfer(&self, account_id: AccountId) -> U128 {
+        U128(self.failed_transfers.get(&account_id).unwrap_or(0))
+    }
+
+    /// Page through the domain registry as (domain, allowed) pairs
+    pub fn get_domain_registry(&self, from_index: u64, limit: u64) -> Vec<(String, bool)> {
+        self.allowed_domains
+            .iter()
+            .skip(from_index as usize)
+            .take(limit.min(MAX_PAGE_LIMIT) as usize)
+            .collect()
+    }
+
+    /// Whether source diversity is required, and the largest share one host may supply
+    pub fn get_source_diversity(&self) -> (bool, u16) {
+        (self.require_source_diversity, self.max_source_domain_share_bps)
+    }
+
+    pub fn get_max_evaluations_per_intent(&self) -> u32 {
+        self.max_evaluations_per_intent
+    }
+
+    /// How many evaluations have been submitted against an intent
+    pub fn get_intent_evaluation_count(&self, intent_id: String) -> u32 {
+        self.intent_evaluation_counts.get(&intent_id).unwrap_or(0)
+    }
+
+    /// How many confirmed evaluations have cited `url`
+    pub fn get_source_corroboration(&self, url: String) -> u64 {
+        self.source_corroboration_count.get(&url).unwrap_or(0)
+    }
+
+    /// Corroboration bonus in bps and the citations a source needs to count as corroborated
+    pub fn get_corroboration_bonus_policy(&self) -> (u16, u64) {
+        (self.corroboration_bonus_bps, self.corroboration_min_count)
+    }
+
+    /// Speed bonus cap in bps and the execution time in ms at which the bonus reaches zero
+    pub fn get_speed_bonus_policy(&self) -> (u16, U64) {
+        (self.speed_bonus_cap_bps, U64(self.speed_bonus_target_ms))
+    }
+
+    /// Reward bonus, as a fraction, for an evaluation that took `execution_time_ms`. Used both
+    /// for finalization rewards and for performance pool scores.
+    pub fn compute_speed_bonus(&self, execution_time_ms: U64) -> f64 {
+        if execution_time_ms.0 >= self.speed_bonus_target_ms {
+            return 0.0;
+        }
+        let remaining = (self.speed_bonus_target_ms - execution_time_ms.0) as f64 / self.speed_bonus_target_ms as f64;
+        remaining * self.speed_bonus_cap_bps as f64 / BPS_DENOMINATOR as f64
+    }
+
+    pub fn is_domain_allowlist_enforced(&self) -> bool {
+        self.enforce_domain_allowlist
+    }
+
+    pub fn get_domain_reliability(&self, domain: String) -> f64 {
+        self.domain_reliability.get(&domain.to_lowercase()).unwrap_or(0.0)
+    }
+
+    pub fn get_min_stake_for_type(&self, intent_type: IntentType) -> U128 {
+        U128(self.effective_min_stake(&intent_type))
+    }
+
+    pub fn get_intent_quotes(&self, intent_id: String) -> Vec<SolverQuote> {
+        self.quotes.get(&intent_id).unwrap_or_default()
+    }
+
+    pub fn get_solver(&self, solver_id: AccountId) -> Option<OracleSolver> {
+        self.solvers.get(&solver_id)
+    }
+
+    /// End of the solver's current cooldown, or None when they may take on work
+    pub fn get_solver_cooldown(&self, solver_id: AccountId) -> Option<U64> {
+        self.solvers.get(&solver_id)
+            .map(|solver| solver.cooldown_until)
+            .filter(|until| until.0 > env::block_timestamp())
+    }
+
+    /// Reputation over the current window next to the all-time success ratio
+    pub fn get_reputation_scores(&self, solver_id: AccountId) -> Option<ReputationScores> {
+        self.solvers.get(&solver_id).map(|solver| ReputationScores {
+            window_score: solver.reputation_score,
+            lifetime_score: self.reputation_from_weights(
+                solver.successful_evaluations as f64,
+                solver.total_evaluations as f64,
+            ),
+            window_started_at: solver.reputation_window_start,
+            archived_windows: self.reputation_archives.get(&solver_id).map_or(0, |a| a.len() as u32),
+        })
+    }
+
+    pub fn get_reputation_archive(&self, solver_id: AccountId) -> Vec<ReputationWindow> {
+        self.reputation_archives.get(&solver_id).unwrap_or_default()
+    }
+
+    /// Mean and weight of the reputation prior
+    pub fn get_reputation_prior(&self) -> (f64, f64) {
+        (self.reputation_prior_mean, self.reputation_prior_weight)
+    }
+
+    /// Minimum stake and window age required by `reset_reputation_window`
+    pub fn get_reputation_reset_policy(&self) -> (U128, U64) {
+        (U128(self.reputation_reset_min_stake), self.reputation_reset_cooldown)
+    }
+
+    pub fn get_solver_tier(&self, solver_id: AccountId) -> Option<ReputationTier> {
+        self.solvers.get(&solver_id)
+            .map(|solver| self.tier_thresholds.tier_for(&solver))
+    }
+
+    pub fn get_tier_thresholds(&self) -> TierThresholds {
+        self.tier_thresholds
+    }
+
+    /// First page of pending intents; see `get_pending_intents_paginated`
+    pub fn get_pending_intents(&self) -> Vec<OracleIntent> {
+        self.get_pending_intents_paginated(0, MAX_PAGE_LIMIT)
+    }
+
+    /// Pending intents in `lang` or a more specific tag of it (so "en" also finds "en-US"),
+    /// skipping `from_index` matches and returning at most `limit` (capped at 100)
+    pub fn get_pending_intents_by_language(&self, lang: String, from_index: u64, limit: u64) -> Vec<OracleIntent> {
+        let lang = lang.to_lowercase();
+        self.intents
+            .values()
+            .filter(|intent| {
+                intent.status == IntentStatus::Pending &&
+                    intent.language.as_deref().is_some_and(|tag| Self::language_matches(tag, &lang))
+            })
+            .skip(from_index as usize)
+            .take(limit.min(MAX_PAGE_LIMIT) as usize)
+            .collect()
+    }
+
+    /// Pending intents, skipping `from_index` matches and returning at most `limit` (capped at 100)
+    pub fn get_pending_intents_paginated(&self, from_index: u64, limit: u64) -> Vec<OracleIntent> {
+        self.intents
+            .values()
+            .filter(|intent| intent.status == IntentStatus::Pending)
+            .skip(from_index as usize)
+            .take(limit.min(MAX_PAGE_LIMIT) as usize)
+            .collect()
+    }
+    
+    /// Intents whose question contains `keyword`, ignoring case. This scans every intent, so it
+    /// suits modest datasets or as a fallback when no off-chain index is available.
+    pub fn search_intents_by_keyword(&self, keyword: String, from_index: u64, limit: u64) -> Vec<OracleIntent> {
+        require!(!keyword.is_empty(), "Keyword cannot be empty");
+        require!(keyword.len() <= MAX_QUESTION_LENGTH, "Keyword too long");
+        let keyword = keyword.to_lowercase();
+        self.intents
+            .values()
+            .filter(|intent| {
+                intent.question.as_ref()
+                    .is_some_and(|question| question.to_lowercase().contains(&keyword))
+            })
+            .skip(from_index as usize)
+            .take(limit.min(MAX_PAGE_LIMIT) as usize)
+            .collect()
+    }
+
+    /// Pending intents at or above `min_priority`, so solvers can service urgent ones first
+    pub fn get_pending_intents_by_priority(
+        &self,
+        min_priority: Priority,
+        from_index: u64,
+        limit: u64,
+    ) -> Vec<OracleIntent> {
+        self.intents
+            .values()
+            .filter(|intent| intent.status == IntentStatus::Pending && intent.priority >= min_priority)
+            .skip(from_index as usize)
+            .take(limit.min(MAX_PAGE_LIMIT) as usize)
+            .collect()
+    }
+    
+    pub fn get_user_profile(&self, user_id: AccountId) -> Option<UserProfile> {
+        self.users.get(&user_id)
+    }
+    
+    pub fn get_all_admins(&self) -> Vec<AccountId> {
+        self.admins.to_vec()
+    }
+    
+    pub fn get_all_verifiers(&self) -> Vec<AccountId> {
+        self.verifiers.to_vec()
+    }
+    
+    pub fn is_user_registered(&self, user_id: AccountId) -> bool {
+        self.users.contains_key(&user_id)
+    }
+    
+    /// First page of intents with the given status; see `get_intents_by_status_paginated`
+    pub fn get_intents_by_status(&self, status: IntentStatus) -> Vec<OracleIntent> {
+        self.get_intents_by_status_paginated(status, 0, MAX_PAGE_LIMIT)
+    }
+
+    pub fn get_intents_by_status_paginated(
+        &self,
+        status: IntentStatus,
+        from_index: u64,
+        limit: u64,
+    ) -> Vec<OracleIntent> {
+        self.intents
+            .values()
+            .filter(|intent| intent.status == status)
+            .skip(from_index as usize)
+            .take(limit.min(MAX_PAGE_LIMIT) as usize)
+            .collect()
+    }
+    
+    /// First page of intents created by `initiator`; see `get_intents_by_initiator_paginated`
+    pub fn get_intents_by_initiator(&self, initiator: AccountId) -> Vec<OracleIntent> {
+        self.get_intents_by_initiator_paginated(initiator, 0, MAX_PAGE_LIMIT)
+    }
+
+    pub fn get_intents_by_initiator_paginated(
+        &self,
+        initiator: AccountId,
+        from_index: u64,
+        limit: u64,
+    ) -> Vec<OracleIntent> {
+        self.intents
+            .values()
+            .filter(|intent| intent.initiator == initiator)
+            .skip(from_index as usize)
+            .take(limit.min(MAX_PAGE_LIMIT) as usize)
+            .collect()
+    }
+    
+    /// First page of a solver's in-progress intents; see `get_solver_active_intents_paginated`
+    pub fn get_solver_active_intents(&self, solver: AccountId) -> Vec<OracleIntent> {
+        self.get_solver_active_intents_paginated(solver, 0, MAX_PAGE_LIMIT)
+    }
+
+    pub fn get_solver_active_intents_paginated(
+        &self,
+        solver: AccountId,
+        from_index: u64,
+        limit: u64,
+    ) -> Vec<OracleIntent> {
+        let solver_evaluations: Vec<String> = self.evaluations
+            .values()
+            .filter(|eval| eval.solver == solver)
+            .map(|eval| eval.intent_id.clone())
+            .collect();
+            
+        self.intents
+            .values()
+            .filter(|intent| {
+                intent.status == IntentStatus::InProgress &&
+                solver_evaluations.contains(&intent.intent_id)
+            })
+            .skip(from_index as usize)
+            .take(limit.min(MAX_PAGE_LIMIT) as usize)
+            .collect()
+    }
+    
+    /// (url, content hash) for each source of an evaluation, in submission order; the hash is
+    /// `None` for sources submitted without one
+    pub fn get_evidence_hashes(&self, evaluation_id: String) -> Vec<(String, Option<String>)> {
+        self.evaluations.get(&evaluation_id)
+            .map(|evaluation| {
+                evaluation.sources
+                    .into_iter()
+                    .map(|source| (source.url, source.content_hash))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn get_confirmation_status(&self, intent_id: String) -> Option<ConfirmationStatus> {
+        let intent = self.intents.get(&intent_id)?;
+        let (confirming, dissenting) = self.confirmation_status(&intent);
+        Some(ConfirmationStatus {
+            required_confirmations: intent.required_confirmations,
+            confirming: confirming.len() as u32,
+            dissenting: dissenting.len() as u32,
+            quorum_reached: confirming.len() as u32 >= intent.required_confirmations,
+        })
+    }
+
+    /// Every recorded lifecycle step of an intent, oldest first
+    pub fn get_intent_history(&self, intent_id: String) -> Vec<IntentHistoryEntry> {
+        self.intent_history.get(&intent_id)
+            .map(|history| history.to_vec())
+            .unwrap_or_default()
+    }
+
+    pub fn get_intent_execution_progress(&self, intent_id: String) -> Option<(IntentStatus, Option<String>, u64)> {
+        if let Some(intent) = self.intents.get(&intent_id) {
+            let time_remaining = Self::time_remaining(&intent);
+            
+            Some((intent.status.clone(), intent.evaluation_hash.clone(), time_remaining))
+        } else {
+            None
+        }
+    }
+    
+    /// Pending intents whose deadline falls within the next `minutes`, soonest first, each
+    /// with its time remaining in nanoseconds. Served from the deadline index.
+    pub fn get_intents_expiring_within(&self, minutes: u64, from_index: u64, limit: u64) -> Vec<(OracleIntent, u64)> {
+        let now = env::block_timestamp();
+        let window_end = now.saturating_add(minutes.saturating_mul(60_000_000_000));
+
+        self.pending_deadlines
+            .range((Bound::Included((now, String::new())), Bound::Excluded((window_end.saturating_add(1), String::new()))))
+            .filter_map(|((deadline, intent_id), _)| {
+                // Skip index entries left behind by extensions, claims and cancellations
+                self.intents.get(&intent_id)
+                    .filter(|intent| intent.status == IntentStatus::Pending && intent.deadline.0 == deadline)
+            })
+            .skip(from_index as usize)
+            .take(limit.min(MAX_PAGE_LIMIT) as usize)
+            .map(|intent| {
+                let time_remaining = Self::time_remaining(&intent);
+                (intent, time_remaining)
+            })
+            .collect()
+    }
+
+    pub fn get_solver_performance_metrics(&self, solver_id: AccountId) -> Option<SolverPerformanceMetrics> {
+        if let Some(solver) = self.solvers.get(&solver_id) {
+            Some(solver.performance_metrics.clone())
+        } else {
+            None
+        }
+    }
+    
+    pub fn get_top_performers(&self, limit: u32) -> Vec<(AccountId, f64, SolverPerformanceMetrics)> {
+        let mut solvers = self.active_solvers();
+        solvers.sort_by(|a, b| {
+            b.reputation_score.partial_cmp(&a.reputation_score).unwrap_or(Ordering::Equal)
+        });
+
+        solvers
+            .into_iter()
+            .take((limit as u64).min(MAX_PAGE_LIMIT) as usize)
+            .map(|solver| (solver.solver_id, solver.reputation_score, solver.performance_metrics))
+            .collect()
+    }
+
+    /// Active solvers ranked by `metric`, best first. For `Speed` a lower average execution
+    /// time ranks higher; solvers without any recorded executions rank last.
+    pub fn get_leaderboard(&self, metric: LeaderboardMetric, limit: u32) -> Vec<(AccountId, f64)> {
+        let mut entries: Vec<(AccountId, f64)> = self.active_solvers()
+            .into_iter()
+            .map(|solver| {
+                let metrics = &solver.performance_metrics;
+                let value = match metric {
+                    LeaderboardMetric::Reputation => solver.reputation_score,
+                    LeaderboardMetric::RewardsEarned => metrics.total_rewards_earned as f64,
+                    LeaderboardMetric::Speed => metrics.average_execution_time,
+                    LeaderboardMetric::ChallengeDefenseRate => {
+                        if metrics.total_challenges_received > 0 {
+                            metrics.challenges_successfully_defended as f64 /
+                                metrics.total_challenges_received as f64
+                        } else {
+                            0.0
+                        }
+                    },
+                    LeaderboardMetric::UptimeScore => metrics.uptime_score,
+                };
+                (solver.solver_id, value)
+            })
+            .collect();
+
+        match metric {
+            LeaderboardMetric::Speed => entries.sort_by(|a, b| {
+                match (a.1 > 0.0, b.1 > 0.0) {
+                    (true, true) => a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal),
+                    (true, false) => Ordering::Less,
+                    (false, true) => Ordering::Greater,
+                    (false, false) => Ordering::Equal,
+                }
+            }),
+            _ => entries.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal)),
+        }
+
+        entries.truncate((limit as u64).min(MAX_PAGE_LIMIT) as usize);
+        entries
+    }
+    
+    /// Active solvers specialized in `category`, ranked by their reputation within it. With a
+    /// `language`, solvers whose stated languages don't cover it are left out; solvers who
+    /// haven't stated any languages are kept.
+    pub fn get_matching_solvers(&self, category: String, limit: u32, language: Option<String>) -> Vec<OracleSolver> {
+        let language = language.map(|tag| tag.to_lowercase());
+        let mut solvers: Vec<(OracleSolver, f64)> = self.active_solvers()
+            .into_iter()
+            .filter(|solver| solver.performance_metrics.specialization_areas.contains(&category))
+            .filter(|solver| {
+                let Some(language) = &language else {
+                    return true;
+                };
+                let stated = self.solver_preferences.get(&solver.solver_id)
+                    .map(|preferences| preferences.languages)
+                    .unwrap_or_default();
+                stated.is_empty() || stated.iter().any(|spoken| Self::language_matches(language, spoken))
+            })
+            .map(|solver| {
+                let reputation = self.area_reputation(&solver, &category);
+                (solver, reputation)
+            })
+            .collect();
+        solvers.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+
+        solvers
+            .into_iter()
+            .take((limit as u64).min(MAX_PAGE_LIMIT) as usize)
+            .map(|(solver, _)| solver)
+            .collect()
+    }
+
+    /// Solvers that can take on another evaluation right now: active, not cooling down, at or
+    /// above `min_reputation` and below the active-evaluation cap. Pages in registration order.
+    pub fn get_available_solvers(&self, min_reputation: f64, from_index: u64, limit: u64) -> Vec<OracleSolver> {
+        let now = env::block_timestamp();
+        self.active_solvers()
+            .into_iter()
+            .filter(|solver| {
+                solver.cooldown_until.0 <= now
+                    && solver.reputation_score >= min_reputation
+                    && self.active_evaluation_counts.get(&solver.solver_id).unwrap_or(0)
+                        < self.max_active_evaluations_per_solver
+            })
+            .skip(from_index as usize)
+            .take(limit.min(MAX_PAGE_LIMIT) as usize)
+            .collect()
+    }
+
+    pub fn get_solver_area_performance(&self, solver_id: AccountId, area: String) -> Option<AreaPerformance> {
+        self.area_performance.get(&(solver_id, area))
+    }
+
+    /// Everything a solver UI needs about one solver, or None if they aren't registered
+    pub fn get_solver_dashboard(&self, solver_id: AccountId) -> Option<SolverDashboard> {
+        let solver = self.solvers.get(&solver_id)?;
+        let specialization_performance = solver.performance_metrics.specialization_areas
+            .iter()
+            .map(|area| (area.clone(), self.area_performance.get(&(solver_id.clone(), area.clone()))))
+            .collect();
+
+        Some(SolverDashboard {
+            tier: self.tier_thresholds.tier_for(&solver),
+            reputation: self.get_reputation_scores(solver_id.clone())?,
+            stake: U128(self.solver_stakes.get(&solver_id).unwrap_or(0)),
+            active_evaluations: self.active_evaluation_counts.get(&solver_id).unwrap_or(0),
+            pending_withdrawal: U128(self.pending_withdrawals.get(&solver_id).unwrap_or(0)),
+            cooldown_until: self.get_solver_cooldown(solver_id),
+            specialization_performance,
+            solver,
+        })
+    }
+
+    /// Rewards, losses and return on stake for a solver, or None if they aren't registered.
+    /// Activity is measured from the solver's user registration, falling back to the start
+    /// of their earliest reputation window.
+    pub fn get_solver_roi(&self, solver_id: AccountId) -> Option<RoiStats> {
+        let solver = self.solvers.get(&solver_id)?;
+        let metrics = &solver.performance_metrics;
+        let active_since = self.users.get(&solver_id)
+            .map(|user| user.registration_date)
+            .or_else(|| self.reputation_archives.get(&solver_id)?.first().map(|window| window.started_at))
+            .unwrap_or(solver.reputation_window_start);
+
+        let rewards = metrics.total_rewards_earned;
+        let losses = metrics.total_stakes_lost;
+        let stake = solver.total_stake as f64;
+        let years = env::block_timestamp().saturating_sub(active_since.0) as f64 / NANOS_PER_YEAR;
+        let net = rewards as f64 - losses as f64;
+
+        Some(RoiStats {
+            total_rewards_earned: U128(rewards),
+            total_stakes_lost: U128(losses),
+            net_profit: U128(rewards.saturating_sub(losses)),
+            net_loss: U128(losses.saturating_sub(rewards)),
+            reward_to_stake_ratio: if stake > 0.0 { rewards as f64 / stake } else { 0.0 },
+            active_since,
+            evaluations_per_year: if years > 0.0 { solver.total_evaluations as f64 / years } else { 0.0 },
+            annualized_return: if stake > 0.0 && years > 0.0 { net / stake / years } else { 0.0 },
+        })
+    }
+
+    pub fn get_solver_specialization(&self, solver_id: AccountId) -> Vec<String> {
+        if let Some(solver) = self.solvers.get(&solver_id) {
+            solver.performance_metrics.specialization_areas.clone()
+        } else {
+            vec![]
+        }
+    }
+    
+    pub fn update_solver_specialization(&mut self, specialization_areas: Vec<String>) {
+        self.assert_not_paused();
+        let solver_id = env::predecessor_account_id();
+        
+        if let Some(mut solver) = self.solvers.get(&solver_id) {
+            solver.performance_metrics.specialization_areas = specialization_areas;
+            self.solvers.insert(&solver_id, &solver);
+        }
+    }
+
+    /// Store the caller's notification preferences. Categories must be among the solver's
+    /// specialization areas, and `max_concurrent` cannot exceed the active evaluation limit.
+    /// `languages` are the BCP-47 tags the solver can answer in.
+    pub fn set_solver_preferences(
+        &mut self,
+        categories: Vec<String>,
+        min_reward: U128,
+        max_concurrent: u32,
+        languages: Option<Vec<String>>,
+    ) {
+        self.assert_not_paused();
+        let solver_id = env::predecessor_account_id();
+        let solver = self.solvers.get(&solver_id)
+            .expect("Solver not registered");
+
+        require!(
+            categories.len() <= MAX_PREFERENCE_CATEGORIES,
+            format!("At most {} preferred categories allowed", MAX_PREFERENCE_CATEGORIES)
+        );
+        for category in &categories {
+            require!(
+                solver.performance_metrics.specialization_areas.contains(category),
+                format!("Category {} is not one of the solver's specialization areas", category)
+            );
+        }
+        require!(
+            max_concurrent > 0 && max_concurrent <= self.max_active_evaluations_per_solver,
+            format!("Max concurrent must be between 1 and {}", self.max_active_evaluations_per_solver)
+        );
+
+        let languages = languages.unwrap_or_default();
+        require!(
+            languages.len() <= MAX_PREFERENCE_LANGUAGES,
+            format!("At most {} languages allowed", MAX_PREFERENCE_LANGUAGES)
+        );
+        let mut languages: Vec<String> = languages.into_iter().map(Self::normalize_language_tag).collect();
+        languages.sort();
+        languages.dedup();
+
+        let mut categories = categories;
+        categories.sort();
+        categories.dedup();
+        self.solver_preferences.insert(&solver_id, &SolverPreferences {
+            categories,
+            min_reward,
+            max_concurrent,
+            languages,
+        });
+
+        env::log_str(&format!("Solver {} updated notification preferences", solver_id));
+    }
+
+    /// Deactivate solvers idle for longer than the threshold, scanning at most `max_operations`
+    /// solvers per call and resuming where the previous call stopped. Their uptime score is
+    /// scaled down by the share of the idle time that exceeded the threshold.
+    pub fn deactivate_stale_solvers(&mut self, inactivity_threshold_days: u64, max_operations: u32) -> u32 {
+        self.assert_admin_or_owner();
+        require!(inactivity_threshold_days > 0, "Inactivity threshold must be positive");
+
+        let solver_count = self.solver_ids.len();
+        if solver_count == 0 {
+            return 0;
+        }
+
+        let now = env::block_timestamp();
+        let threshold = inactivity_threshold_days * 24 * 60 * 60 * 1_000_000_000;
+        let mut deactivated_count = 0;
+
+        for _ in 0..(max_operations as u64).min(solver_count) {
+            let index = self.stale_scan_cursor % solver_count;
+            self.stale_scan_cursor = (index + 1) % solver_count;
+
+            let solver_id = self.solver_ids.get(index).unwrap();
+            let Some(mut solver) = self.solvers.get(&solver_id) else {
+                continue;
+            };
+            let inactive_for = now.saturating_sub(solver.performance_metrics.last_active_timestamp.0);
+            if !solver.is_active || inactive_for <= threshold {
+                continue;
+            }
+
+            self.set_solver_active(&mut solver, false);
+            solver.performance_metrics.uptime_score *= threshold as f64 / inactive_for as f64;
+            self.solvers.insert(&solver_id, &solver);
+            deactivated_count += 1;
+
+            env::log_str(&format!(
+                "Solver {} deactivated after {} days of inactivity",
+                solver_id,
+                inactive_for / (24 * 60 * 60 * 1_000_000_000)
+            ));
+        }
+
+        deactivated_count
+    }
+
+    /// Queue a sensitive operation to run once the admin action delay has passed, giving
+    /// everyone time to react before it takes effect. Returns the action ID.
+    pub fn queue_admin_action(&mut self, action: AdminAction) -> u64 {
+        self.assert_may_run_admin_action(&action);
+        let proposer = env::predecessor_account_id();
+        let now = env::block_timestamp();
+
+        let action_id = self.next_admin_action_id;
+        self.next_admin_action_id += 1;
+        let queued = QueuedAdminAction {
+            action_id,
+            action,
+            proposer: proposer.clone(),
+            queued_at: U64(now),
+            executable_at: U64(now + self.admin_action_delay.0),
+        };
+        self.admin_actions.insert(&action_id, &queued);
+
+        env::log_str(&format!(
+            "AdminActionQueued: {} by {}, executable at {}",
+            action_id, proposer, queued.executable_at.0
+        ));
+        action_id
+    }
+
+    /// Run a queued action whose delay has passed. The caller needs the privilege the action's
+    /// entry point requires, checked again here in case roles changed since it was queued.
+    pub fn execute_admin_action(&mut self, action_id: u64) {
+        let queued = self.admin_actions.get(&action_id)
+            .expect("Admin action not found");
+        self.assert_may_run_admin_action(&queued.action);
+        require!(
+            env::block_timestamp() >= queued.executable_at.0,
+            "Admin action is still time-locked"
+        );
+        self.admin_actions.remove(&action_id);
+
+        match queued.action {
+            AdminAction::CleanupOldData { retention_days, max_operations } => {
+                require!(max_operations > 0, "max_operations must be positive");
+                let (cursor, cleaned_count, _) =
+                    self.internal_cleanup_old_data(self.cleanup_cursor, retention_days, max_operations);
+                self.cleanup_cursor = cursor;
+                env::log_str(&format!("Cleaned up {} old records", cleaned_count));
+            },
+            AdminAction::DistributePerformanceRewards { total_reward_pool } => {
+                self.internal_distribute_performance_rewards(total_reward_pool.0);
+            },
+            AdminAction::SettleDispute { evaluation_id, challenge_id, winner } => {
+                self.assert_not_paused();
+                self.internal_settle_dispute(evaluation_id, challenge_id, &winner, 0);
+            },
+            AdminAction::SetAdminActionDelay { admin_action_delay } => {
+                self.internal_set_admin_action_delay(admin_action_delay);
+            },
+        }
+
+        env::log_str(&format!(
+            "AdminActionExecuted: {} by {}",
+            action_id, env::predecessor_account_id()
+        ));
+    }
+
+    /// Every queueable action's direct entry point (`cleanup_old_data_resumable`,
+    /// `distribute_performance_rewards`, `settle_dispute`, `set_admin_action_delay`) is owner-only
+    fn assert_may_run_admin_action(&self, action: &AdminAction) {
+        match action {
+            AdminAction::CleanupOldData { .. } |
+            AdminAction::DistributePerformanceRewards { .. } |
+            AdminAction::SettleDispute { .. } |
+            AdminAction::SetAdminActionDelay { .. } => self.assert_owner(),
+        }
+    }
+
+    /// Drop a queued action before it runs (owner or the account that queued it)
+    pub fn cancel_admin_action(&mut self, action_id: u64) {
+        let caller = env::predecessor_account_id();
+        let queued = self.admin_actions.get(&action_id)
+            .expect("Admin action not found");
+        require!(
+            caller == self.owner || caller == queued.proposer,
+            "Only the owner or the proposer can cancel this action"
+        );
+        self.admin_actions.remove(&action_id);
+
+        env::log_str(&format!("AdminActionCancelled: {} by {}", action_id, caller));
+    }
+
+    /// Deactivate a solver and bar the account from registering or reactivating (owner/admin
+    /// only). Blacklisting does not forfeit stake: it stays locked while the solver's evaluations
+    /// can still be challenged and slashed, and can then be withdrawn with `withdraw_solver_stake`.
+    pub fn blacklist_solver(&mut self, solver_id: AccountId, reason: String) {
+        self.assert_admin_or_owner();
+        require!(!reason.is_empty(), "Reason cannot be empty");
+        require!(!self.blacklist.contains_key(&solver_id), "Account is already blacklisted");
+
+        if let Some(mut solver) = self.solvers.get(&solver_id) {
+            if solver.is_active {
+                self.set_solver_active(&mut solver, false);
+                self.solvers.insert(&solver_id, &solver);
+            }
+        }
+        self.blacklist.insert(&solver_id, &reason);
+
+        env::log_str(&format!("Solver {} blacklisted: {}", solver_id, reason));
+    }
+
+    /// Lift a blacklisting (owner/admin only); a deactivated solver must still call `reactivate_solver`
+    pub fn remove_from_blacklist(&mut self, solver_id: AccountId) {
+        self.assert_admin_or_owner();
+        require!(self.blacklist.remove(&solver_id).is_some(), "Account is not blacklisted");
+
+        env::log_str(&format!("Solver {} removed from blacklist", solver_id));
+    }
+
+    /// Reactivate the caller's solver account if its stake still meets the minimum
+    pub fn reactivate_solver(&mut self) {
+        self.assert_not_paused();
+        let solver_id = env::predecessor_account_id();
+        let mut solver = self.solvers.get(&solver_id)
+            .expect("Solver not registered");
+        require!(!solver.is_active, "Solver is already active");
+        require!(!self.blacklist.contains_key(&solver_id), "Account is blacklisted");
+        require!(
+            self.solver_stakes.get(&solver_id).unwrap_or(0) >= self.min_stake,
+            "Stake is below the minimum"
+        );
+
+        self.set_solver_active(&mut solver, true);
+        solver.performance_metrics.last_active_timestamp = U64(env::block_timestamp());
+        self.solvers.insert(&solver_id, &solver);
+
+        env::log_str(&format!("Solver {} reactivated", solver_id));
+    }
+
+    /// Signal that the calling solver is online. Solvers are expected to call this about once
+    /// every `HEARTBEAT_INTERVAL` (one hour); no deposit is needed. Each interval covered by a
+    /// heartbeat pulls `uptime_score` towards 1 and each missed interval decays it towards 0,
+    /// as a moving average over roughly the last `UPTIME_WINDOW_INTERVALS` (24) intervals.
+    /// Calling more often than the interval earns nothing extra. Returns the new score.
+    pub fn heartbeat(&mut self) -> f64 {
+        self.assert_not_paused();
+        let solver_id = env::predecessor_account_id();
+        let mut solver = self.solvers.get(&solver_id)
+            .expect("Solver not registered");
+        let now = env::block_timestamp();
+
+        let since = self.last_heartbeat(&solver);
+        let intervals = now.saturating_sub(since) as f64 / HEARTBEAT_INTERVAL as f64;
+        let missed = (intervals - 1.0).max(0.0);
+        let covered = intervals.min(1.0);
+        let keep = 1.0 - 1.0 / UPTIME_WINDOW_INTERVALS;
+
+        let decayed = solver.performance_metrics.uptime_score * keep.powf(missed);
+        let score = 1.0 - (1.0 - decayed) * keep.powf(covered);
+        solver.performance_metrics.uptime_score = Self::finite_or(score, 0.0).clamp(0.0, 1.0);
+        solver.performance_metrics.last_active_timestamp = U64(now);
+        self.solvers.insert(&solver_id, &solver);
+        self.solver_heartbeats.insert(&solver_id, &U64(now));
+
+        solver.performance_metrics.uptime_score
+    }
+
+    /// Archive the caller's current reputation window and start a fresh one, so a solver
+    /// who has reformed is judged on recent work. Lifetime totals are kept.
+    pub fn reset_reputation_window(&mut self) {
+        self.assert_not_paused();
+        let solver_id = env::predecessor_account_id();
+        let mut solver = self.solvers.get(&solver_id)
+            .expect("Solver not registered");
+        let now = env::block_timestamp();
+        require!(
+            now >= solver.reputation_window_start.0.saturating_add(self.reputation_reset_cooldown.0),
+            "Reputation window is too recent to reset"
         );
-        
+        require!(
+            self.solver_stakes.get(&solver_id).unwrap_or(0) >= self.reputation_reset_min_stake,
+            "Stake is below the reputation reset minimum"
+        );
+        Self::assert_not_cooling_down(&solver);
+
+        let mut archive = self.reputation_archives.get(&solver_id).unwrap_or_default();
+        archive.push(ReputationWindow {
+            started_at: solver.reputation_window_start,
+            ended_at: U64(now),
+            reputation_score: solver.reputation_score,
+            decayed_successes: solver.decayed_successes,
+            decayed_evaluations: solver.decayed_evaluations,
+        });
+        self.reputation_archives.insert(&solver_id, &archive);
+
+        let reputation_score = self.reputation_from_weights(0.0, 0.0);
+        self.reputation_sum += reputation_score - solver.reputation_score;
+        solver.reputation_score = reputation_score;
+        solver.decayed_successes = 0.0;
+        solver.decayed_evaluations = 0.0;
+        solver.last_reputation_update = U64(now);
+        solver.reputation_window_start = U64(now);
+        self.solvers.insert(&solver_id, &solver);
+
         env::log_str(&format!(
-            "Evaluation {} finalized with reward {} for solver {}", 
-            evaluation_id, 
-            total_reward, 
-            updated_evaluation.solver
+            "ReputationWindowReset: solver {} starting fresh after {} archived windows",
+            solver_id,
+            archive.len()
         ));
-        
-        total_reward
     }
-    
-    /// Calculate weighted performance score for reward distribution
-    fn calculate_weighted_performance_score(&self, solver: &OracleSolver) -> f64 {
-        let base_score = solver.reputation_score;
-        let activity_bonus = (solver.total_evaluations as f64).ln().max(0.0) * 0.1;
-        let challenge_defense_bonus = if solver.performance_metrics.total_challenges_received > 0 {
-            (solver.performance_metrics.challenges_successfully_defended as f64 / 
-             solver.performance_metrics.total_challenges_received as f64) * 0.2
-        } else {
-            0.1 // Small bonus for no challenges (implies good work)
-        };
-        
-        let speed_bonus = if solver.performance_metrics.average_execution_time > 0.0 {
-            (300000.0 / solver.performance_metrics.average_execution_time).min(0.3) // Up to 30% bonus for speed
-        } else {
-            0.0
-        };
-        
-        base_score + activity_bonus + challenge_defense_bonus + speed_bonus
+
+    /// Private helper methods
+    fn assert_owner(&self) {
+        require!(env::predecessor_account_id() == self.owner, "Only owner can call this method");
+    }
+
+    fn assert_not_paused(&self) {
+        require!(!self.paused, "Contract is paused");
     }
     
-    /// Batch process multiple operations for gas efficiency
-    pub fn batch_process_expired_and_cleanup(&mut self, max_operations: u32) -> (u32, u32) {
-        let mut operations_count = 0;
-        let expired_count = self.process_expired_intents();
-        operations_count += expired_count;
-        
-        if operations_count < max_operations {
-            let remaining_ops = max_operations - operations_count;
-            let cleanup_count = self.cleanup_old_data_limited(30, remaining_ops);
-            (expired_count, cleanup_count)
-        } else {
-            (expired_count, 0)
-        }
+    fn assert_admin_or_owner(&self) {
+        let caller = env::predecessor_account_id();
+        let is_admin = self.admins.iter().any(|admin| admin == caller);
+        require!(
+            caller == self.owner || is_admin,
+            "Only owner or admin can call this method"
+        );
     }
     
-    /// Limited cleanup to control gas usage
-    fn cleanup_old_data_limited(&mut self, retention_days: u64, max_deletions: u32) -> u32 {
-        self.assert_owner();
-        
-        let retention_period = retention_days * 24 * 60 * 60 * 1_000_000_000;
-        let cutoff_time = env::block_timestamp().saturating_sub(retention_period);
-        let mut cleaned_count = 0;
-        
-        // Clean up old intents (limited)
-        let old_intent_ids: Vec<String> = self.intents
-            .values()
-            .filter(|intent| {
-                intent.created_at.0 < cutoff_time && 
-                (intent.status == IntentStatus::Completed || 
-                 intent.status == IntentStatus::Settled ||
-                 intent.status == IntentStatus::Expired)
-            })
-            .take(max_deletions as usize)
-            .map(|intent| intent.intent_id.clone())
-            .collect();
-        
-        for intent_id in old_intent_ids {
-            self.intents.remove(&intent_id);
-            cleaned_count += 1;
-            if cleaned_count >= max_deletions {
-                break;
-            }
-        }
-        
-        cleaned_count
+    fn assert_verifier_or_admin(&self) {
+        let caller = env::predecessor_account_id();
+        let is_admin = self.admins.iter().any(|admin| admin == caller);
+        let is_verifier = self.verifiers.iter().any(|verifier| verifier == caller);
+        require!(
+            caller == self.owner || is_admin || is_verifier,
+            "Only owner, admin, or verifier can call this method"
+        );
     }
     
-    /// Get storage usage statistics
-    pub fn get_storage_stats(&self) -> (u64, u64, u64, u64) {
-        (
-            self.intents.len(),
-            self.evaluations.len(), 
-            self.challenges.len(),
-            0 // users count not available with LookupMap
-        )
+    fn is_user_verified(&self, user_id: &AccountId, min_level: u8) -> bool {
+        if let Some(user) = self.users.get(user_id) {
+            user.is_verified && user.verification_level >= min_level
+        } else {
+            false
+        }
     }
 
-    /// Process expired intents and return stakes
-    pub fn process_expired_intents(&mut self) -> u32 {
-        let current_time = env::block_timestamp();
-        let mut expired_count = 0;
-        let mut expired_intent_ids = Vec::new();
-        
-        // Find expired intents
-        for intent in self.intents.values() {
-            if intent.deadline.0 < current_time && intent.status == IntentStatus::Pending {
-                expired_intent_ids.push(intent.intent_id.clone());
-            }
-        }
-        
-        // Process expired intents
-        for intent_id in expired_intent_ids {
-            if let Some(mut intent) = self.intents.get(&intent_id) {
-                intent.status = IntentStatus::Expired;
-                self.intents.insert(&intent_id, &intent);
-                
-                // Return stake to initiator
-                self.transfer_reward(&intent.initiator, intent.stake);
-                expired_count += 1;
-                
-                env::log_str(&format!("Intent {} expired, stake returned to {}", intent_id, intent.initiator));
-            }
+    /// Lowercased host of an `http(s)://` URL, or `None` if the URL is malformed
+    fn url_host(url: &str) -> Option<String> {
+        let lower = url.to_lowercase();
+        let rest = lower.strip_prefix("https://")
+            .or_else(|| lower.strip_prefix("http://"))?;
+        let authority = rest.split(['/', '?', '#']).next()?;
+        // Drop any userinfo and port
+        let host = authority.rsplit('@').next()?.split(':').next()?;
+        let well_formed = host.split('.').all(|label| {
+            !label.is_empty()
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        });
+        if well_formed {
+            Some(host.to_string())
+        } else {
+            None
         }
-        
-        expired_count
     }
 
-    /// Clean up expired intents and evaluations older than retention period
-    pub fn cleanup_old_data(&mut self, retention_days: u64) -> u32 {
-        self.assert_owner();
-        
-        let retention_period = retention_days * 24 * 60 * 60 * 1_000_000_000; // Convert days to nanoseconds
-        let cutoff_time = env::block_timestamp().saturating_sub(retention_period);
-        let mut cleaned_count = 0;
-        
-        // Clean up old intents
-        let mut old_intent_ids = Vec::new();
-        for intent in self.intents.values() {
-            if intent.created_at.0 < cutoff_time && 
-               (intent.status == IntentStatus::Completed || 
-                intent.status == IntentStatus::Settled ||
-                intent.status == IntentStatus::Expired) {
-                old_intent_ids.push(intent.intent_id.clone());
-            }
-        }
-        
-        for intent_id in old_intent_ids {
-            self.intents.remove(&intent_id);
-            cleaned_count += 1;
+    /// While source diversity is required, reject a multi-source evaluation in which one host
+    /// supplies more than `max_source_domain_share_bps` of the sources
+    fn assert_source_diversity(&self, sources: &[Source]) {
+        if !self.require_source_diversity || sources.len() < 2 {
+            return;
         }
-        
-        // Clean up old evaluations
-        let mut old_evaluation_ids = Vec::new();
-        for evaluation in self.evaluations.values() {
-            if evaluation.submitted_at.0 < cutoff_time &&
-               (evaluation.status == EvaluationStatus::Confirmed || 
-                evaluation.status == EvaluationStatus::Refuted) {
-                old_evaluation_ids.push(evaluation.evaluation_id.clone());
+        let mut host_counts: Vec<(String, u128)> = Vec::new();
+        for source in sources {
+            let host = Self::url_host(&source.url)
+                .unwrap_or_else(|| env::panic_str(&format!("Malformed source URL: {}", source.url)));
+            match host_counts.iter_mut().find(|(counted, _)| *counted == host) {
+                Some((_, count)) => *count += 1,
+                None => host_counts.push((host, 1)),
             }
         }
-        
-        for evaluation_id in old_evaluation_ids {
-            self.evaluations.remove(&evaluation_id);
-            cleaned_count += 1;
+        let total = sources.len() as u128;
+        for (host, count) in host_counts {
+            require!(
+                count * BPS_DENOMINATOR <= self.max_source_domain_share_bps as u128 * total,
+                format!(
+                    "Too many sources from {}: {} of {} exceeds the {} bps limit",
+                    host, count, total, self.max_source_domain_share_bps
+                )
+            );
         }
-        
-        // Clean up old challenges
-        let mut old_challenge_ids = Vec::new();
-        for challenge in self.challenges.values() {
-            if challenge.submitted_at.0 < cutoff_time &&
-               (challenge.status == ChallengeStatus::Successful || 
-                challenge.status == ChallengeStatus::Failed) {
-                old_challenge_ids.push(challenge.challenge_id.clone());
+    }
+
+    /// Registry entry for a host, checking the host itself and then each parent domain so
+    /// an entry for `example.com` also covers `news.example.com`
+    fn domain_status(&self, host: &str) -> Option<bool> {
+        let mut domain = host;
+        loop {
+            if let Some(allowed) = self.allowed_domains.get(&domain.to_string()) {
+                return Some(allowed);
             }
+            domain = domain.split_once('.')?.1;
         }
-        
-        for challenge_id in old_challenge_ids {
-            self.challenges.remove(&challenge_id);
-            cleaned_count += 1;
+    }
+
+    /// Whether more than half of an evaluation's distinct source URLs are corroborated
+    fn is_well_corroborated(&self, sources: &[Source]) -> bool {
+        let urls = Self::distinct_urls(sources);
+        let corroborated = urls
+            .iter()
+            .filter(|url| self.source_corroboration_count.get(url).unwrap_or(0) >= self.corroboration_min_count)
+            .count();
+        self.corroboration_bonus_bps > 0 && corroborated * 2 > urls.len()
+    }
+
+    /// Count a confirmed evaluation's citations, once per distinct URL
+    fn record_source_corroboration(&mut self, sources: &[Source]) {
+        for url in Self::distinct_urls(sources) {
+            let count = self.source_corroboration_count.get(&url).unwrap_or(0);
+            self.source_corroboration_count.insert(&url, &(count + 1));
         }
-        
-        env::log_str(&format!("Cleaned up {} old records", cleaned_count));
-        cleaned_count
     }
 
-    /// Automatic cleanup that can be called by anyone (gas-efficient)
-    pub fn auto_cleanup(&mut self) -> u32 {
-        let expired_count = self.process_expired_intents();
-        
-        // Only perform expensive cleanup operations occasionally
-        if self.intent_counter % 100 == 0 {
-            expired_count + self.cleanup_old_data(30) // Clean data older than 30 days
-        } else {
-            expired_count
+    fn distinct_urls(sources: &[Source]) -> Vec<String> {
+        let mut urls: Vec<String> = sources.iter().map(|source| source.url.clone()).collect();
+        urls.sort();
+        urls.dedup();
+        urls
+    }
+
+    /// Reject sources from blocked domains and, while the allowlist is enforced, from any
+    /// domain not explicitly allowed
+    fn assert_allowed_domains(&self, sources: &[Source]) {
+        for source in sources {
+            let host = Self::url_host(&source.url);
+            let status = host.as_deref().and_then(|host| self.domain_status(host));
+            require!(status != Some(false), format!("Source domain is blocked: {}", source.url));
+            if self.enforce_domain_allowlist {
+                require!(host.is_some(), format!("Malformed source URL: {}", source.url));
+                require!(status == Some(true), format!("Source domain is not allowlisted: {}", source.url));
+            }
         }
     }
 
-    /// View methods
-    pub fn get_intent(&self, intent_id: String) -> Option<OracleIntent> {
-        self.intents.get(&intent_id)
+    /// Summed reliability of the distinct hosts among `sources`; citing a host again adds nothing
+    fn sources_reliability(&self, sources: &[Source]) -> f64 {
+        let mut hosts: Vec<String> = sources
+            .iter()
+            .filter_map(|source| Self::url_host(&source.url))
+            .collect();
+        hosts.sort();
+        hosts.dedup();
+        hosts
+            .iter()
+            .map(|host| self.domain_reliability.get(host).unwrap_or(0.0))
+            .sum()
     }
 
-    pub fn get_evaluation(&self, evaluation_id: String) -> Option<OracleEvaluation> {
-        self.evaluations.get(&evaluation_id)
+    /// When an intent opens to solvers; until then it is listed but cannot be taken
+    fn intent_open_time(&self, intent: &OracleIntent) -> u64 {
+        intent.created_at.0.saturating_add(self.intent_reveal_delay.0)
     }
 
-    pub fn get_challenge(&self, challenge_id: String) -> Option<RefutationChallenge> {
-        self.challenges.get(&challenge_id)
+    fn assert_intent_open(&self, intent: &OracleIntent) {
+        require!(env::block_timestamp() >= self.intent_open_time(intent), "Intent not yet open");
+        require!(
+            !self.duplicate_links.contains_key(&intent.intent_id),
+            "Intent is linked as a duplicate; answer its canonical intent instead"
+        );
     }
 
-    pub fn get_solver(&self, solver_id: AccountId) -> Option<OracleSolver> {
-        self.solvers.get(&solver_id)
+    /// Check a BCP-47 language tag's shape (subtags of letters and digits joined by hyphens)
+    /// and lowercase it, since tags compare case-insensitively
+    fn normalize_language_tag(tag: String) -> String {
+        require!(
+            !tag.is_empty() && tag.len() <= MAX_LANGUAGE_TAG_LENGTH,
+            "Language tag must be 1-35 characters"
+        );
+        require!(
+            tag.split('-').all(|subtag| !subtag.is_empty() && subtag.chars().all(|c| c.is_ascii_alphanumeric())),
+            "Language tag must be letters and digits separated by hyphens"
+        );
+        tag.to_lowercase()
     }
 
-    pub fn get_pending_intents(&self) -> Vec<OracleIntent> {
-        self.intents
-            .values()
-            .filter(|intent| intent.status == IntentStatus::Pending)
+    /// Whether `tag` falls under `range`: equal to it, or a more specific tag such as "en-us" under "en"
+    fn language_matches(tag: &str, range: &str) -> bool {
+        tag == range || tag.strip_prefix(range).is_some_and(|rest| rest.starts_with('-'))
+    }
+
+    fn hex_sha256(value: &str) -> String {
+        env::sha256(value.as_bytes())
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
             .collect()
     }
-    
-    pub fn get_user_profile(&self, user_id: AccountId) -> Option<UserProfile> {
-        self.users.get(&user_id)
+
+    /// Lowercased question with whitespace collapsed, for duplicate matching
+    fn normalized_question(intent: &OracleIntent) -> Option<String> {
+        intent.question.as_ref()
+            .map(|question| question.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase())
     }
-    
-    pub fn get_all_admins(&self) -> Vec<AccountId> {
-        self.admins.to_vec()
+
+    /// Drop an intent's duplicate links in either direction
+    fn forget_intent_links(&mut self, intent_id: &String) {
+        if let Some(canonical_id) = self.duplicate_links.remove(intent_id) {
+            if let Some(mut duplicates) = self.linked_duplicates.get(&canonical_id) {
+                duplicates.retain(|duplicate_id| duplicate_id != intent_id);
+                if duplicates.is_empty() {
+                    self.linked_duplicates.remove(&canonical_id);
+                } else {
+                    self.linked_duplicates.insert(&canonical_id, &duplicates);
+                }
+            }
+        }
+        for duplicate_id in self.linked_duplicates.remove(intent_id).unwrap_or_default() {
+            self.duplicate_links.remove(&duplicate_id);
+            env::log_str(&format!("IntentUnlinked: {} no longer waits on {}", duplicate_id, intent_id));
+        }
     }
-    
-    pub fn get_all_verifiers(&self) -> Vec<AccountId> {
-        self.verifiers.to_vec()
+
+    /// Resolve the pending duplicates of a canonical intent with its confirmed evaluation,
+    /// paying each duplicate's reward (less the protocol fee) to the evaluation's solver.
+    /// Duplicates the evaluation doesn't satisfy are unlinked and reopen to solvers.
+    fn resolve_linked_duplicates(&mut self, canonical_id: &String, evaluation: &OracleEvaluation) {
+        let Some(duplicate_ids) = self.linked_duplicates.remove(canonical_id) else {
+            return;
+        };
+        for duplicate_id in duplicate_ids {
+            self.duplicate_links.remove(&duplicate_id);
+            let Some(mut intent) = self.intents.get(&duplicate_id) else {
+                continue;
+            };
+            if intent.status != IntentStatus::Pending {
+                continue;
+            }
+            let satisfied = evaluation.sources.len() >= intent.required_sources as usize &&
+                intent.allowed_solvers.as_ref().is_none_or(|allowed| allowed.contains(&evaluation.solver)) &&
+                intent.confidence_threshold.is_none_or(|threshold| evaluation.confidence >= threshold) &&
+                env::block_timestamp() <= intent.deadline.0;
+            if !satisfied {
+                env::log_str(&format!(
+                    "IntentUnlinked: {} no longer waits on {}; its requirements were not met",
+                    duplicate_id, canonical_id
+                ));
+                continue;
+            }
+
+            let protocol_fee = intent.reward * self.protocol_fee_bps as u128 / BPS_DENOMINATOR;
+            self.protocol_treasury += protocol_fee;
+            let solver_reward = intent.reward - protocol_fee;
+            self.credit_withdrawal(&evaluation.solver, solver_reward);
+            self.total_rewards_distributed += solver_reward;
+            if intent.stake > intent.reward {
+                self.credit_withdrawal(&intent.initiator, intent.stake - intent.reward);
+            }
+            if let Some(mut solver) = self.solvers.get(&evaluation.solver) {
+                solver.performance_metrics.total_rewards_earned += solver_reward;
+                self.solvers.insert(&evaluation.solver, &solver);
+            }
+
+            intent.evaluation_hash = Some(evaluation.evaluation_id.clone());
+            intent.assigned_solver = Some(evaluation.solver.clone());
+            self.set_intent_status(&mut intent, IntentStatus::Completed);
+            self.intents.insert(&duplicate_id, &intent);
+            self.record_intent_history(
+                &duplicate_id,
+                &env::predecessor_account_id(),
+                format!("resolved_as_duplicate_of:{}", canonical_id),
+                &intent.status,
+            );
+            env::log_str(&format!(
+                "DuplicateResolved: {} answered by {} from {}, reward {} to {}",
+                duplicate_id, evaluation.evaluation_id, canonical_id, solver_reward, evaluation.solver
+            ));
+        }
     }
-    
-    pub fn is_user_registered(&self, user_id: AccountId) -> bool {
-        self.users.contains_key(&user_id)
+
+    fn assert_not_excluded(intent: &OracleIntent, solver_id: &AccountId) {
+        require!(
+            !intent.excluded_solvers.contains(solver_id),
+            "Solver was refuted on this intent and cannot answer it again"
+        );
     }
-    
-    pub fn get_intents_by_status(&self, status: IntentStatus) -> Vec<OracleIntent> {
-        self.intents
-            .values()
-            .filter(|intent| intent.status == status)
-            .collect()
+
+    fn assert_solver_allowed(intent: &OracleIntent, solver_id: &AccountId) {
+        require!(
+            intent.allowed_solvers.as_ref().is_none_or(|allowed| allowed.contains(solver_id)),
+            "Solver is not on this intent's allowlist"
+        );
     }
-    
-    pub fn get_intents_by_initiator(&self, initiator: AccountId) -> Vec<OracleIntent> {
-        self.intents
-            .values()
-            .filter(|intent| intent.initiator == initiator)
-            .collect()
+
+    fn assert_not_cooling_down(solver: &OracleSolver) {
+        require!(
+            env::block_timestamp() >= solver.cooldown_until.0,
+            "Solver is on cooldown after a lost dispute"
+        );
     }
-    
-    pub fn get_solver_active_intents(&self, solver: AccountId) -> Vec<OracleIntent> {
-        let solver_evaluations: Vec<String> = self.evaluations
-            .values()
-            .filter(|eval| eval.solver == solver)
-            .map(|eval| eval.intent_id.clone())
-            .collect();
-            
-        self.intents
-            .values()
-            .filter(|intent| {
-                intent.status == IntentStatus::InProgress &&
-                solver_evaluations.contains(&intent.intent_id)
-            })
+
+    fn assert_specialization_match(&self, solver: &OracleSolver, intent: &OracleIntent) {
+        if !self.require_specialization_match {
+            return;
+        }
+        if let Some(category) = &intent.category {
+            require!(
+                solver.performance_metrics.specialization_areas.contains(category),
+                "Solver is not specialized in this intent's category"
+            );
+        }
+    }
+
+    /// High-value intents are reserved for Gold tier and above, however the solver takes them on
+    fn assert_tier_for_intent(&self, solver: &OracleSolver, intent: &OracleIntent) {
+        if intent.reward > HIGH_VALUE_INTENT_STAKE_MULTIPLE * self.min_stake {
+            require!(
+                self.tier_thresholds.tier_for(solver) >= ReputationTier::Gold,
+                "Gold tier or above required for high-value intent"
+            );
+        }
+    }
+
+    fn active_solvers(&self) -> Vec<OracleSolver> {
+        self.solver_ids
+            .iter()
+            .filter_map(|solver_id| self.solvers.get(&solver_id))
+            .filter(|solver| solver.is_active)
             .collect()
     }
-    
-    pub fn get_intent_execution_progress(&self, intent_id: String) -> Option<(IntentStatus, Option<String>, u64)> {
-        if let Some(intent) = self.intents.get(&intent_id) {
-            let time_remaining = if intent.deadline.0 > env::block_timestamp() {
-                intent.deadline.0 - env::block_timestamp()
-            } else {
-                0
-            };
-            
-            Some((intent.status.clone(), intent.evaluation_hash.clone(), time_remaining))
-        } else {
-            None
+
+    fn assert_valid_sources(sources: &[Source]) {
+        require!(!sources.is_empty(), "At least one source is required");
+        require!(sources.len() <= MAX_SOURCES_PER_EVALUATION, "Too many sources");
+
+        for source in sources {
+            require!(source.url.len() <= MAX_URL_LENGTH, "Source URL too long");
+            require!(!source.title.is_empty(), "Source title cannot be empty");
+            if let Some(content_hash) = &source.content_hash {
+                require!(
+                    Self::is_valid_content_hash(content_hash),
+                    "Source content hash must be a hex SHA-256 digest or an IPFS CID"
+                );
+            }
+        }
+    }
+
+    /// Accepts a 64-character hex SHA-256 digest, a base58 CIDv0 (`Qm...`) or a base32 CIDv1 (`b...`)
+    fn is_valid_content_hash(content_hash: &str) -> bool {
+        const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+        if content_hash.len() == 64 {
+            return content_hash.chars().all(|c| c.is_ascii_hexdigit());
+        }
+        if content_hash.len() == 46 && content_hash.starts_with("Qm") {
+            return content_hash.chars().all(|c| BASE58_ALPHABET.contains(c));
+        }
+        match content_hash.strip_prefix('b') {
+            Some(rest) => {
+                (58..=MAX_URL_LENGTH).contains(&rest.len())
+                    && rest.chars().all(|c| c.is_ascii_lowercase() || ('2'..='7').contains(&c))
+            },
+            None => false,
+        }
+    }
+
+    /// Type-specific minimum stake, falling back to the global `min_stake` when unset
+    fn effective_min_stake(&self, intent_type: &IntentType) -> Balance {
+        self.min_stake_by_type.get(intent_type).unwrap_or(self.min_stake)
+    }
+
+    /// End of an evaluation's challenge window, using its intent's period when one was set
+    fn challenge_deadline(&self, evaluation: &OracleEvaluation) -> u64 {
+        let challenge_period = self.intents.get(&evaluation.intent_id)
+            .and_then(|intent| intent.challenge_period)
+            .unwrap_or(self.challenge_period);
+        evaluation.amended_at.unwrap_or(evaluation.submitted_at).0 + challenge_period.0
+    }
+
+    /// Attached deposit used as stake; only valid while staking in native NEAR
+    fn native_stake_deposit(&self) -> Balance {
+        require!(
+            self.stake_token.is_none(),
+            "Stake must be sent with ft_transfer_call to the stake token"
+        );
+        env::attached_deposit().as_yoctonear()
+    }
+
+    /// Pay out from escrow; never releases more than the contract has collected
+    fn transfer_reward(&mut self, recipient: &AccountId, amount: Balance) -> Promise {
+        require!(amount <= self.total_escrowed, "Payout exceeds escrowed funds");
+        self.total_escrowed -= amount;
+
+        match &self.stake_token {
+            Some(token) => {
+                Promise::new(token.clone()).function_call(
+                    "ft_transfer".to_string(),
+                    near_sdk::serde_json::json!({
+                        "receiver_id": recipient,
+                        "amount": U128(amount),
+                    })
+                    .to_string()
+                    .into_bytes(),
+                    ONE_YOCTO,
+                    FT_TRANSFER_GAS,
+                )
+            },
+            None => Promise::new(recipient.clone()).transfer(NearToken::from_yoctonear(amount)),
         }
     }
-    
-    pub fn get_solver_performance_metrics(&self, solver_id: AccountId) -> Option<SolverPerformanceMetrics> {
-        if let Some(solver) = self.solvers.get(&solver_id) {
-            Some(solver.performance_metrics.clone())
+
+    /// Owe `amount` to `account_id`; the funds stay escrowed until they call `withdraw`
+    fn credit_withdrawal(&mut self, account_id: &AccountId, amount: Balance) {
+        let owed = self.pending_withdrawals.get(account_id).unwrap_or(0);
+        self.pending_withdrawals.insert(account_id, &(owed + amount));
+    }
+
+    /// Owe a reward to `account_id`: payouts above the vesting threshold are released in equal
+    /// tranches over the vesting period, anything else is credited at once. Vesting funds stay
+    /// escrowed until claimed with `claim_vested`.
+    fn credit_reward(&mut self, account_id: &AccountId, amount: Balance) {
+        if self.vesting_threshold == 0 || amount <= self.vesting_threshold {
+            self.credit_withdrawal(account_id, amount);
+            return;
+        }
+
+        let now = env::block_timestamp();
+        let tranches = self.vesting_tranches as u64;
+        let tranche = amount / tranches as u128;
+        let mut schedule = self.vesting_schedule.get(account_id).unwrap_or_default();
+        for index in 1..=tranches {
+            // The last tranche absorbs the rounding remainder
+            let tranche_amount = if index == tranches { amount - tranche * (tranches as u128 - 1) } else { tranche };
+            schedule.push((U64(now + self.vesting_period.0 / tranches * index), tranche_amount));
+        }
+        schedule.sort_by_key(|(release_at, _)| release_at.0);
+        self.vesting_schedule.insert(account_id, &schedule);
+
+        env::log_str(&format!(
+            "RewardVesting: {} to {} in {} tranches until {}",
+            amount, account_id, tranches, now + self.vesting_period.0
+        ));
+    }
+
+    /// Take back up to `amount` of an account's unreleased tranches, latest first. Returns the
+    /// amount recovered, which stays escrowed for the caller to reassign.
+    fn claw_back_vesting(&mut self, account_id: &AccountId, amount: Balance) -> Balance {
+        let Some(mut schedule) = self.vesting_schedule.get(account_id) else {
+            return 0;
+        };
+        let now = env::block_timestamp();
+        let mut recovered = 0;
+        for (release_at, tranche) in schedule.iter_mut().rev() {
+            if recovered == amount || release_at.0 <= now {
+                break;
+            }
+            let taken = (*tranche).min(amount - recovered);
+            *tranche -= taken;
+            recovered += taken;
+        }
+        schedule.retain(|(_, tranche)| *tranche > 0);
+        if schedule.is_empty() {
+            self.vesting_schedule.remove(account_id);
         } else {
-            None
+            self.vesting_schedule.insert(account_id, &schedule);
         }
+        recovered
     }
-    
-    pub fn get_top_performers(&self, limit: u32) -> Vec<(AccountId, f64, SolverPerformanceMetrics)> {
-        // Note: This would need to track active solver IDs separately in a production implementation
-        // For now, returning empty vec as LookupMap doesn't support iteration
-        vec![]
+
+    /// Recover a payout from `account_id`: unreleased vesting tranches are taken back before
+    /// the registration stake is touched. Returns the amount recovered, which stays escrowed.
+    fn claw_back_payout(&mut self, account_id: &AccountId, amount: Balance, reason: String) -> Balance {
+        let clawed = self.claw_back_vesting(account_id, amount);
+        clawed + self.slash_solver(account_id.clone(), amount - clawed, reason)
     }
-    
-    pub fn get_solver_specialization(&self, solver_id: AccountId) -> Vec<String> {
-        if let Some(solver) = self.solvers.get(&solver_id) {
-            solver.performance_metrics.specialization_areas.clone()
+
+    /// Keep a dispute party's stake in place until `until`, when its appeal window closes
+    fn lock_stake_until(&mut self, account_id: &AccountId, until: u64) {
+        let locked_until = self.stake_locked_until.get(account_id).map_or(0, |locked| locked.0);
+        if until > locked_until {
+            self.stake_locked_until.insert(account_id, &U64(until));
+        }
+    }
+
+    fn adjust_open_appeal_count(&mut self, account_id: &AccountId, opened: bool) {
+        let count = self.open_appeal_counts.get(account_id).unwrap_or(0);
+        if opened {
+            self.open_appeal_counts.insert(account_id, &(count + 1));
+        } else if count <= 1 {
+            self.open_appeal_counts.remove(account_id);
         } else {
-            vec![]
+            self.open_appeal_counts.insert(account_id, &(count - 1));
         }
     }
-    
-    pub fn update_solver_specialization(&mut self, specialization_areas: Vec<String>) {
-        let solver_id = env::predecessor_account_id();
-        
-        if let Some(mut solver) = self.solvers.get(&solver_id) {
-            solver.performance_metrics.specialization_areas = specialization_areas;
-            self.solvers.insert(&solver_id, &solver);
+
+    /// Validate a submitted answer and fold a `Boolean` answer value back into the plain
+    /// `answer` flag, so yes/no evaluations are stored the same way however they were sent
+    fn normalize_answer(answer: bool, answer_value: Option<AnswerValue>) -> (bool, Option<AnswerValue>) {
+        match answer_value {
+            Some(AnswerValue::Boolean(value)) => (value, None),
+            Some(AnswerValue::Numeric(value)) => {
+                require!(value.is_finite(), "Numeric answer must be a finite number");
+                (answer, Some(AnswerValue::Numeric(value)))
+            },
+            Some(AnswerValue::Categorical(label)) => {
+                require!(
+                    !label.is_empty() && label.len() <= MAX_CATEGORY_LENGTH,
+                    "Categorical answer must be 1-50 characters"
+                );
+                (answer, Some(AnswerValue::Categorical(label)))
+            },
+            None => (answer, None),
         }
     }
 
-    /// Private helper methods
-    fn assert_owner(&self) {
-        require!(env::predecessor_account_id() == self.owner, "Only owner can call this method");
+    /// Nanoseconds until the intent's deadline, or 0 once it has passed
+    fn time_remaining(intent: &OracleIntent) -> u64 {
+        intent.deadline.0.saturating_sub(env::block_timestamp())
     }
-    
-    fn assert_admin_or_owner(&self) {
-        let caller = env::predecessor_account_id();
-        let is_admin = self.admins.iter().any(|admin| admin == caller);
-        require!(
-            caller == self.owner || is_admin,
-            "Only owner or admin can call this method"
+
+    /// Read a price returned either as a JSON number or a decimal string
+    fn parse_feed_price(bytes: &[u8]) -> Option<f64> {
+        let value: near_sdk::serde_json::Value = near_sdk::serde_json::from_slice(bytes).ok()?;
+        let price = match value {
+            near_sdk::serde_json::Value::Number(number) => number.as_f64()?,
+            near_sdk::serde_json::Value::String(text) => text.parse().ok()?,
+            _ => return None,
+        };
+        (price.is_finite() && price >= 0.0).then_some(price)
+    }
+
+    /// Pay out from escrow and confirm the transfer in `on_reward_transferred`
+    fn transfer_reward_confirmed(&mut self, recipient: &AccountId, amount: Balance, evaluation_id: Option<String>) {
+        self.transfer_reward(recipient, amount).then(
+            Self::ext(env::current_account_id())
+                .with_static_gas(REWARD_CALLBACK_GAS)
+                .on_reward_transferred(recipient.clone(), U128(amount), evaluation_id),
         );
     }
-    
-    fn assert_verifier_or_admin(&self) {
-        let caller = env::predecessor_account_id();
-        let is_admin = self.admins.iter().any(|admin| admin == caller);
-        let is_verifier = self.verifiers.iter().any(|verifier| verifier == caller);
+
+    /// Fold an evaluation's outcome into its solver's reputation exactly once, however many
+    /// settlement or finalization paths it passes through. The caller persists `evaluation`.
+    fn record_evaluation_outcome(&mut self, evaluation: &mut OracleEvaluation, success: bool) {
+        self.record_evaluation_outcome_share(evaluation, if success { 1.0 } else { 0.0 });
+    }
+
+    /// Count an evaluation that was `share` (0-1) right; above one half it counts as a success
+    fn record_evaluation_outcome_share(&mut self, evaluation: &mut OracleEvaluation, share: f64) {
+        if evaluation.reputation_counted {
+            return;
+        }
+        let weight = self.reputation_weight(&evaluation.intent_id);
+        let success = share > 0.5;
+        self.update_solver_reputation_share(&evaluation.solver, share, weight);
+        if success {
+            self.reward_referrer(&evaluation.solver);
+        }
+        if let Some(category) = self.intents.get(&evaluation.intent_id).and_then(|intent| intent.category) {
+            self.update_area_performance(&evaluation.solver, category, success, evaluation.confidence);
+        }
+        evaluation.reputation_counted = true;
+    }
+
+    /// Remember who referred `account_id`. An account keeps its first referrer, and a
+    /// referral that would close a loop back to the account is rejected.
+    fn record_referral(&mut self, account_id: &AccountId, referrer: AccountId) {
+        if self.referrals.contains_key(account_id) {
+            return;
+        }
+        require!(referrer != *account_id, "Cannot refer yourself");
         require!(
-            caller == self.owner || is_admin || is_verifier,
-            "Only owner, admin, or verifier can call this method"
+            self.users.contains_key(&referrer) || self.solvers.contains_key(&referrer),
+            "Referrer is not registered"
         );
+        // Chains are acyclic by construction, so this walk always ends
+        let mut ancestor = referrer.clone();
+        while let Some(referral) = self.referrals.get(&ancestor) {
+            require!(referral.referrer != *account_id, "Referral would create a cycle");
+            ancestor = referral.referrer;
+        }
+
+        self.referrals.insert(account_id, &Referral { referrer: referrer.clone(), bonus_paid: false });
+        let mut stats = self.referral_stats.get(&referrer).unwrap_or_default();
+        stats.referral_count += 1;
+        self.referral_stats.insert(&referrer, &stats);
+
+        env::log_str(&format!("Account {} referred by {}", account_id, referrer));
     }
-    
-    fn is_user_verified(&self, user_id: &AccountId) -> bool {
-        if let Some(user) = self.users.get(user_id) {
-            user.is_verified
-        } else {
-            false
+
+    /// Pay the referral bonus out of the treasury the first time a referred account succeeds
+    fn reward_referrer(&mut self, account_id: &AccountId) {
+        let Some(mut referral) = self.referrals.get(account_id) else {
+            return;
+        };
+        if referral.bonus_paid {
+            return;
+        }
+        referral.bonus_paid = true;
+        self.referrals.insert(account_id, &referral);
+
+        let bonus = self.referral_bonus.min(self.protocol_treasury);
+        self.protocol_treasury -= bonus;
+        if bonus > 0 {
+            self.credit_withdrawal(&referral.referrer, bonus);
+        }
+        let mut stats = self.referral_stats.get(&referral.referrer).unwrap_or_default();
+        stats.rewarded_referrals += 1;
+        stats.total_bonus_earned = U128(stats.total_bonus_earned.0 + bonus);
+        self.referral_stats.insert(&referral.referrer, &stats);
+
+        env::log_str(&format!(
+            "Referral bonus {} credited to {} for {}",
+            bonus, referral.referrer, account_id
+        ));
+    }
+
+    fn update_area_performance(&mut self, solver_id: &AccountId, area: String, success: bool, confidence: f64) {
+        let key = (solver_id.clone(), area);
+        let mut performance = self.area_performance.get(&key).unwrap_or_default();
+        performance.evaluations += 1;
+        if success {
+            performance.successes += 1;
         }
+        performance.avg_confidence +=
+            (confidence - performance.avg_confidence) / performance.evaluations as f64;
+        self.area_performance.insert(&key, &performance);
+    }
+
+    /// Reputation within a category, on the same prior as the global score; solvers
+    /// without results in the category fall back to their global reputation
+    fn area_reputation(&self, solver: &OracleSolver, area: &str) -> f64 {
+        self.area_performance.get(&(solver.solver_id.clone(), area.to_string()))
+            .map_or(solver.reputation_score, |performance| {
+                self.reputation_from_weights(performance.successes as f64, performance.evaluations as f64)
+            })
+    }
+
+    /// Reputation impact of a result on an intent, proportional to its reward in units of
+    /// `min_stake` so high-value intents move reputation more than cheap ones
+    fn reputation_weight(&self, intent_id: &String) -> f64 {
+        let reward = self.intents.get(intent_id).map_or(self.min_stake, |intent| intent.reward);
+        (reward as f64 / self.min_stake as f64).min(MAX_REPUTATION_IMPACT_WEIGHT)
+    }
+
+    /// Bench a solver who lost a dispute for `dispute_cooldown` scaled by the intent's value
+    fn start_dispute_cooldown(&mut self, solver_id: &AccountId, intent_id: &String) {
+        let Some(mut solver) = self.solvers.get(solver_id) else {
+            return;
+        };
+        let duration = (self.dispute_cooldown.0 as f64 * self.reputation_weight(intent_id).max(1.0)) as u64;
+        let until = env::block_timestamp().saturating_add(duration);
+        solver.cooldown_until = U64(solver.cooldown_until.0.max(until));
+        self.solvers.insert(solver_id, &solver);
+
+        env::log_str(&format!("Solver {} on cooldown until {}", solver_id, solver.cooldown_until.0));
     }
 
-    fn transfer_reward(&self, recipient: &AccountId, amount: Balance) {
-        Promise::new(recipient.clone()).transfer(NearToken::from_yoctonear(amount));
+    /// Fold a result of the given weight into the solver's time-decayed weighted success totals
+    fn update_solver_reputation(&mut self, solver_id: &AccountId, success: bool, weight: f64) {
+        self.update_solver_reputation_share(solver_id, if success { 1.0 } else { 0.0 }, weight);
     }
 
-    fn update_solver_reputation(&mut self, solver_id: &AccountId, success: bool) {
+    /// Like `update_solver_reputation` for a result that was `share` (0-1) right
+    fn update_solver_reputation_share(&mut self, solver_id: &AccountId, share: f64, weight: f64) {
         if let Some(mut solver) = self.solvers.get(solver_id) {
             solver.total_evaluations += 1;
-            if success {
+            if share > 0.5 {
                 solver.successful_evaluations += 1;
             }
-            solver.reputation_score = 
-                solver.successful_evaluations as f64 / solver.total_evaluations as f64;
+            // Decay earlier results by the time since the last update, then fold in the newest one
+            let now = env::block_timestamp();
+            let elapsed = now.saturating_sub(solver.last_reputation_update.0);
+            let decay = 0.5_f64.powf(elapsed as f64 / self.reputation_half_life.0 as f64);
+            solver.decayed_successes = solver.decayed_successes * decay + weight * share;
+            solver.decayed_evaluations = solver.decayed_evaluations * decay + weight;
+            solver.last_reputation_update = U64(now);
+            let reputation_score =
+                self.reputation_from_weights(solver.decayed_successes, solver.decayed_evaluations);
+            self.reputation_sum += reputation_score - solver.reputation_score;
+            solver.reputation_score = reputation_score;
             
             // Update last active timestamp
-            solver.performance_metrics.last_active_timestamp = U64(env::block_timestamp());
+            solver.performance_metrics.last_active_timestamp = U64(now);
             
             self.solvers.insert(solver_id, &solver);
         }
     }
     
+    /// Split a quorum intent's live evaluations into those agreeing with the leading answer
+    /// (the one most others agree with; the earliest wins ties) and the rest. Refuted
+    /// evaluations are left out of both.
+    fn confirmation_status(&self, intent: &OracleIntent) -> (Vec<String>, Vec<String>) {
+        let answers: Vec<(String, AnswerValue)> = intent.quorum_evaluations
+            .iter()
+            .filter_map(|id| self.evaluations.get(id))
+            .filter(|evaluation| evaluation.status != EvaluationStatus::Refuted)
+            .map(|evaluation| (evaluation.evaluation_id.clone(), evaluation.answer_value()))
+            .collect();
+
+        let agreeing_with = |answer: &AnswerValue| {
+            answers.iter().filter(|(_, other)| answer.agrees_with(other, self.answer_tolerance)).count()
+        };
+        let Some((_, leader)) = answers.iter().rev().max_by_key(|(_, answer)| agreeing_with(answer)) else {
+            return (vec![], vec![]);
+        };
+
+        let (confirming, dissenting): (Vec<_>, Vec<_>) = answers
+            .iter()
+            .partition(|(_, answer)| answer.agrees_with(leader, self.answer_tolerance));
+        (
+            confirming.into_iter().map(|(id, _)| id.clone()).collect(),
+            dissenting.into_iter().map(|(id, _)| id.clone()).collect(),
+        )
+    }
+
+    /// Append a lifecycle step to the intent's history
+    fn record_intent_history(&mut self, intent_id: &String, actor: &AccountId, action: String, status_after: &IntentStatus) {
+        let mut history = self.intent_history.get(intent_id)
+            .unwrap_or_else(|| Vector::new(StorageKey::IntentHistoryEntries {
+                intent_hash: env::sha256(intent_id.as_bytes()),
+            }));
+        history.push(&IntentHistoryEntry {
+            timestamp: U64(env::block_timestamp()),
+            actor: actor.clone(),
+            action,
+            status_after: status_after.clone(),
+        });
+        self.intent_history.insert(intent_id, &history);
+    }
+
+    /// Move an intent to `status`, keeping the per-status counters in step
+    fn set_intent_status(&mut self, intent: &mut OracleIntent, status: IntentStatus) {
+        self.adjust_intent_status_count(&intent.status, false);
+        self.adjust_intent_status_count(&status, true);
+        intent.status = status;
+    }
+
+    fn adjust_intent_status_count(&mut self, status: &IntentStatus, increment: bool) {
+        let count = self.intent_status_counts.get(status).unwrap_or(0);
+        let count = if increment { count + 1 } else { count.saturating_sub(1) };
+        self.intent_status_counts.insert(status, &count);
+    }
+
+    /// Move an evaluation to `status`, keeping the per-status counters in step
+    fn set_evaluation_status(&mut self, evaluation: &mut OracleEvaluation, status: EvaluationStatus) {
+        self.adjust_evaluation_status_count(&evaluation.status, false);
+        self.adjust_evaluation_status_count(&status, true);
+        evaluation.status = status;
+    }
+
+    fn adjust_evaluation_status_count(&mut self, status: &EvaluationStatus, increment: bool) {
+        let count = self.evaluation_status_counts.get(status).unwrap_or(0);
+        let count = if increment { count + 1 } else { count.saturating_sub(1) };
+        self.evaluation_status_counts.insert(status, &count);
+    }
+
+    /// Flip a solver's active flag, keeping `active_solver_count` in step. The caller persists `solver`.
+    fn set_solver_active(&mut self, solver: &mut OracleSolver, active: bool) {
+        if solver.is_active == active {
+            return;
+        }
+        if active {
+            self.active_solver_count += 1;
+        } else {
+            self.active_solver_count -= 1;
+        }
+        solver.is_active = active;
+    }
+
+    /// Success ratio blended with the configured prior, so new solvers start at the prior
+    /// mean and need a sustained record to approach either extreme
+    fn reputation_from_weights(&self, successes: f64, evaluations: f64) -> f64 {
+        let total = evaluations + self.reputation_prior_weight;
+        if total <= 0.0 {
+            return self.reputation_prior_mean;
+        }
+        let score = (successes + self.reputation_prior_mean * self.reputation_prior_weight) / total;
+        Self::finite_or(score, self.reputation_prior_mean).clamp(0.0, 1.0)
+    }
+
+    /// `value`, or `fallback` when it is NaN or infinite, so a bad computation is never persisted
+    /// or left to poison later comparisons
+    fn finite_or(value: f64, fallback: f64) -> f64 {
+        if value.is_finite() { value } else { fallback }
+    }
+
     fn update_solver_performance_metrics(
         &mut self, 
         solver_id: &AccountId, 
@@ -1086,18 +6967,19 @@ impl OracleIntentContract {
             // Update averages using incremental calculation
             let total_evals = solver.total_evaluations as f64;
             
-            if total_evals > 0.0 {
-                metrics.average_execution_time = 
-                    (metrics.average_execution_time * (total_evals - 1.0) + execution_time_ms) / total_evals;
-                metrics.average_confidence_score = 
-                    (metrics.average_confidence_score * (total_evals - 1.0) + confidence) / total_evals;
-                metrics.average_source_count = 
-                    (metrics.average_source_count * (total_evals - 1.0) + source_count as f64) / total_evals;
+            let (average_execution_time, average_confidence_score, average_source_count) = if total_evals > 0.0 {
+                (
+                    (metrics.average_execution_time * (total_evals - 1.0) + execution_time_ms) / total_evals,
+                    (metrics.average_confidence_score * (total_evals - 1.0) + confidence) / total_evals,
+                    (metrics.average_source_count * (total_evals - 1.0) + source_count as f64) / total_evals,
+                )
             } else {
-                metrics.average_execution_time = execution_time_ms;
-                metrics.average_confidence_score = confidence;
-                metrics.average_source_count = source_count as f64;
-            }
+                (execution_time_ms, confidence, source_count as f64)
+            };
+            // A non-finite sample keeps the previous average
+            metrics.average_execution_time = Self::finite_or(average_execution_time, metrics.average_execution_time);
+            metrics.average_confidence_score = Self::finite_or(average_confidence_score, metrics.average_confidence_score);
+            metrics.average_source_count = Self::finite_or(average_source_count, metrics.average_source_count);
             
             metrics.total_rewards_earned += reward_amount;
             metrics.last_active_timestamp = U64(env::block_timestamp());
@@ -1106,6 +6988,331 @@ impl OracleIntentContract {
         }
     }
     
+    /// A fresh solver record holding `stake`, as registration creates it
+    fn new_solver_record(&self, solver_id: AccountId, stake: Balance) -> OracleSolver {
+        OracleSolver {
+            solver_id,
+            reputation_score: self.reputation_from_weights(0.0, 0.0),
+            total_evaluations: 0,
+            successful_evaluations: 0,
+            total_stake: stake,
+            is_active: true,
+            performance_metrics: SolverPerformanceMetrics {
+                average_execution_time: 0.0,
+                average_confidence_score: 0.0,
+                total_challenges_received: 0,
+                challenges_successfully_defended: 0,
+                total_rewards_earned: 0,
+                total_stakes_lost: 0,
+                last_active_timestamp: U64(env::block_timestamp()),
+                specialization_areas: vec![],
+                average_source_count: 0.0,
+                uptime_score: 1.0,
+            },
+            decayed_successes: 0.0,
+            decayed_evaluations: 0.0,
+            last_reputation_update: U64(env::block_timestamp()),
+            cooldown_until: U64(0),
+            reputation_window_start: U64(env::block_timestamp()),
+        }
+    }
+
+    /// `map` read with a launch-layout value type; both share the same storage prefixes
+    fn legacy_view<V: BorshSerialize, L: BorshDeserialize>(map: &UnorderedMap<String, V>) -> UnorderedMap<String, L> {
+        let layout = near_sdk::borsh::to_vec(map).expect("Failed to serialize map");
+        UnorderedMap::try_from_slice(&layout).expect("Failed to read map")
+    }
+
+    fn upgrade_legacy_intent(&mut self, legacy: LegacyOracleIntent) {
+        let intent = OracleIntent {
+            intent_id: legacy.intent_id,
+            intent_type: legacy.intent_type,
+            initiator: legacy.initiator,
+            question: legacy.question,
+            evaluation_hash: legacy.evaluation_hash,
+            challenge_hash: legacy.challenge_hash,
+            stake: legacy.stake,
+            reward: legacy.reward,
+            deadline: legacy.deadline,
+            status: legacy.status,
+            created_at: legacy.created_at,
+            assigned_solver: None,
+            category: None,
+            confidence_threshold: None,
+            required_sources: 1, // the launch release checked but never stored it
+            priority: Priority::Normal,
+            challenge_period: None,
+            required_confirmations: 1,
+            quorum_evaluations: vec![],
+            excluded_solvers: vec![],
+            assigned_at: None,
+            encrypted_question: None,
+            question_hash: None,
+            language: None,
+            allowed_solvers: None,
+        };
+
+        self.adjust_intent_status_count(&intent.status, true);
+        if intent.status == IntentStatus::Pending {
+            self.pending_deadlines.insert(&(intent.deadline.0, intent.intent_id.clone()), &());
+        }
+        if matches!(intent.status, IntentStatus::Pending | IntentStatus::InProgress) {
+            self.total_escrowed += intent.stake;
+        }
+        self.intents.insert(&intent.intent_id, &intent);
+    }
+
+    fn upgrade_legacy_evaluation(&mut self, legacy: LegacyOracleEvaluation) {
+        let open = matches!(legacy.status, EvaluationStatus::Submitted | EvaluationStatus::Challenged);
+        let evaluation = OracleEvaluation {
+            evaluation_id: legacy.evaluation_id,
+            intent_id: legacy.intent_id,
+            solver: legacy.solver,
+            question: legacy.question,
+            answer: legacy.answer,
+            confidence: legacy.confidence,
+            sources: legacy.sources.into_iter().map(Source::from).collect(),
+            execution_time: legacy.execution_time,
+            stake: legacy.stake,
+            status: legacy.status,
+            submitted_at: legacy.submitted_at,
+            // Launch results are not folded into reputation after the fact
+            reputation_counted: !open,
+            contributors: vec![],
+            amended_at: None,
+            price_threshold: None,
+            price_feed_result: None,
+            answer_value: None,
+        };
+
+        self.upgrade_legacy_solver(&evaluation.solver);
+        self.adjust_evaluation_status_count(&evaluation.status, true);
+        Self::push_indexed_id(&mut self.solver_evaluations, &evaluation.solver, &evaluation.evaluation_id, |account_hash| {
+            StorageKey::SolverEvaluationIds { account_hash }
+        });
+        let intent_evaluations = self.intent_evaluation_counts.get(&evaluation.intent_id).unwrap_or(0);
+        self.intent_evaluation_counts.insert(&evaluation.intent_id, &(intent_evaluations + 1));
+        if open {
+            let active_evaluations = self.active_evaluation_counts.get(&evaluation.solver).unwrap_or(0);
+            self.active_evaluation_counts.insert(&evaluation.solver, &(active_evaluations + 1));
+            self.total_escrowed += evaluation.stake;
+        }
+
+        // The launch release paid out confirmed evaluations but left their intents in progress
+        if evaluation.status == EvaluationStatus::Confirmed {
+            if let Some(mut intent) = self.intents.get(&evaluation.intent_id) {
+                if intent.status == IntentStatus::InProgress {
+                    self.set_intent_status(&mut intent, IntentStatus::Completed);
+                    intent.evaluation_hash = Some(evaluation.evaluation_id.clone());
+                    self.total_escrowed -= intent.stake;
+                    self.intents.insert(&intent.intent_id, &intent);
+                }
+            }
+        }
+        self.evaluations.insert(&evaluation.evaluation_id, &evaluation);
+    }
+
+    fn upgrade_legacy_challenge(&mut self, legacy: LegacyRefutationChallenge) {
+        let challenge = RefutationChallenge {
+            challenge_id: legacy.challenge_id,
+            evaluation_id: legacy.evaluation_id,
+            challenger: legacy.challenger,
+            counter_sources: legacy.counter_sources.into_iter().map(Source::from).collect(),
+            stake: legacy.stake,
+            status: legacy.status,
+            submitted_at: legacy.submitted_at,
+            rebuttal_sources: vec![],
+            settled_at: None,
+            settled_winner: None,
+            settlement_payout: 0,
+            appeal_votes: vec![],
+        };
+
+        self.upgrade_legacy_solver(&challenge.challenger);
+        if challenge.status == ChallengeStatus::Submitted {
+            self.total_escrowed += challenge.stake;
+        }
+        if let Some(evaluation) = self.evaluations.get(&challenge.evaluation_id) {
+            Self::push_indexed_id(&mut self.solver_challenges, &evaluation.solver, &challenge.challenge_id, |account_hash| {
+                StorageKey::SolverChallengeIds { account_hash }
+            });
+        }
+        let mut evaluation_challenges = self.evaluation_challenges.get(&challenge.evaluation_id)
+            .unwrap_or_else(|| Vector::new(StorageKey::EvaluationChallengeIds {
+                evaluation_hash: env::sha256(challenge.evaluation_id.as_bytes()),
+            }));
+        evaluation_challenges.push(&challenge.challenge_id);
+        self.evaluation_challenges.insert(&challenge.evaluation_id, &evaluation_challenges);
+        self.challenges.insert(&challenge.challenge_id, &challenge);
+    }
+
+    /// Rebuild `solver_id` if its record is still the bare launch-layout stake; false otherwise.
+    /// At launch the stake was written over the solver record under the same key, and a
+    /// current record never serializes to exactly a `Balance`.
+    fn upgrade_legacy_solver(&mut self, solver_id: &AccountId) -> bool {
+        let key = [b"s".as_slice(), &near_sdk::borsh::to_vec(solver_id).expect("Failed to serialize account")].concat();
+        let Some(stake) = env::storage_read(&key)
+            .and_then(|raw| Balance::try_from_slice(&raw).ok()) else {
+            return false;
+        };
+
+        let solver = self.new_solver_record(solver_id.clone(), stake);
+        self.solver_ids.push(solver_id);
+        self.reputation_sum += solver.reputation_score;
+        self.active_solver_count += 1;
+        self.solvers.insert(solver_id, &solver);
+        self.solver_stakes.insert(solver_id, &stake);
+        self.total_escrowed += stake;
+
+        env::log_str(&format!("Solver {} migrated with stake {}", solver_id, stake));
+        true
+    }
+
+    /// Append an ID to a per-solver index, creating the solver's list on first use
+    fn push_indexed_id(
+        index: &mut LookupMap<AccountId, Vector<String>>,
+        solver_id: &AccountId,
+        id: &String,
+        make_key: fn(Vec<u8>) -> StorageKey,
+    ) {
+        let mut ids = index.get(solver_id)
+            .unwrap_or_else(|| Vector::new(make_key(env::sha256(solver_id.as_bytes()))));
+        ids.push(id);
+        index.insert(solver_id, &ids);
+    }
+
+    /// Split `amount` in proportion to `weights` (to the basis point); the last share takes the
+    /// rounding remainder so the parts always sum to `amount`
+    fn split_pro_rata(amount: Balance, weights: &[Balance]) -> Vec<Balance> {
+        let total_weight: Balance = weights.iter().sum();
+        let mut remaining = amount;
+        let mut shares = Vec::with_capacity(weights.len());
+        for (index, weight) in weights.iter().enumerate() {
+            let share = if index + 1 == weights.len() {
+                remaining
+            } else if total_weight == 0 {
+                0
+            } else {
+                amount * (weight * BPS_DENOMINATOR / total_weight) / BPS_DENOMINATOR
+            };
+            remaining -= share;
+            shares.push(share);
+        }
+        shares
+    }
+
+    fn is_open_challenge(challenge: &RefutationChallenge) -> bool {
+        challenge.status == ChallengeStatus::Submitted || challenge.status == ChallengeStatus::UnderReview
+    }
+
+    /// Challenges against an evaluation that are neither settled nor withdrawn, oldest first
+    fn open_challenges(&self, evaluation_id: &String) -> Vec<RefutationChallenge> {
+        self.evaluation_challenges.get(evaluation_id)
+            .map(|ids| {
+                ids.iter()
+                    .filter_map(|challenge_id| self.challenges.get(&challenge_id))
+                    .filter(Self::is_open_challenge)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Drop an ID from a per-solver index, keeping the remaining IDs in order
+    fn remove_indexed_id(index: &mut LookupMap<AccountId, Vector<String>>, solver_id: &AccountId, id: &String) {
+        let Some(mut ids) = index.get(solver_id) else {
+            return;
+        };
+        let Some(position) = ids.iter().position(|existing| existing == *id) else {
+            return;
+        };
+
+        for slot in position as u64..ids.len() - 1 {
+            let next = ids.get(slot + 1).unwrap();
+            ids.replace(slot, &next);
+        }
+        ids.pop();
+
+        if ids.is_empty() {
+            index.remove(solver_id);
+        } else {
+            index.insert(solver_id, &ids);
+        }
+    }
+
+    /// Slash `slash_fraction_bps` of a verifier's stake into the bonus pool
+    fn slash_verifier(&mut self, verifier: &AccountId, reason: String) {
+        let stake = self.verifier_stakes.get(verifier).unwrap_or(0);
+        let slashed = stake * self.slash_fraction_bps as u128 / BPS_DENOMINATOR;
+        if slashed == 0 {
+            return;
+        }
+
+        self.verifier_stakes.insert(verifier, &(stake - slashed));
+        self.bonus_pool += slashed;
+
+        env::log_str(&format!(
+            "Verifier {} slashed {} ({}), remaining stake {}",
+            verifier, slashed, reason, stake - slashed
+        ));
+    }
+
+    /// Free one of the solver's active evaluation slots once an evaluation leaves Submitted/Challenged
+    fn release_active_evaluation(&mut self, solver_id: &AccountId) {
+        let active_evaluations = self.active_evaluation_counts.get(solver_id).unwrap_or(0);
+        if active_evaluations <= 1 {
+            self.active_evaluation_counts.remove(solver_id);
+        } else {
+            self.active_evaluation_counts.insert(solver_id, &(active_evaluations - 1));
+        }
+    }
+
+    /// Deduct from a solver's registration stake, deactivating them if they fall below the minimum.
+    /// Returns the amount actually slashed, which is capped at the recorded stake.
+    fn slash_solver(&mut self, solver_id: AccountId, amount: Balance, reason: String) -> Balance {
+        let recorded_stake = self.solver_stakes.get(&solver_id).unwrap_or(0);
+        let slashed = amount.min(recorded_stake);
+        if slashed == 0 {
+            return 0;
+        }
+
+        let remaining_stake = recorded_stake - slashed;
+        self.solver_stakes.insert(&solver_id, &remaining_stake);
+
+        if let Some(mut solver) = self.solvers.get(&solver_id) {
+            solver.total_stake = remaining_stake;
+            solver.performance_metrics.total_stakes_lost += slashed;
+            self.solvers.insert(&solver_id, &solver);
+        }
+
+        env::log_str(&format!(
+            "Solver {} slashed {} ({}), remaining stake {}",
+            solver_id, slashed, reason, remaining_stake
+        ));
+        self.deactivate_if_understaked(&solver_id);
+
+        slashed
+    }
+
+    /// Deactivate a solver whose recorded stake no longer covers `min_stake`. They stay out
+    /// of matching until they top up with `add_solver_stake` and call `reactivate_solver`.
+    fn deactivate_if_understaked(&mut self, solver_id: &AccountId) {
+        let Some(mut solver) = self.solvers.get(solver_id) else {
+            return;
+        };
+        let stake = self.solver_stakes.get(solver_id).unwrap_or(0);
+        if !solver.is_active || stake >= self.min_stake {
+            return;
+        }
+
+        self.set_solver_active(&mut solver, false);
+        self.solvers.insert(solver_id, &solver);
+
+        env::log_str(&format!(
+            "Solver {} deactivated: stake {} below minimum {}",
+            solver_id, stake, self.min_stake
+        ));
+    }
+
     fn update_solver_challenge_metrics(&mut self, solver_id: &AccountId, challenge_defended: bool) {
         if let Some(mut solver) = self.solvers.get(solver_id) {
             solver.performance_metrics.total_challenges_received += 1;
@@ -196,7 +196,7 @@ show_deployment_summary() {
     echo "🔧 Useful Commands:"
     echo "View contract info: near view $CONTRACT_ACCOUNT_ID get_contract_info --networkId testnet"
     echo "View storage stats: near view $CONTRACT_ACCOUNT_ID get_storage_stats --networkId testnet"
-    echo "Submit test intent: near call $CONTRACT_ACCOUNT_ID submit_credibility_intent '{\"question\": \"Test question?\", \"required_sources\": 3, \"confidence_threshold\": 0.8, \"deadline_minutes\": 60}' --accountId [YOUR_ACCOUNT] --depositYocto 1000000000000000000000000 --networkId testnet"
+    echo "Submit test intent: near call $CONTRACT_ACCOUNT_ID submit_credibility_intent '{\"input\": {\"question\": \"Test question?\", \"required_sources\": 3, \"confidence_threshold\": 0.8, \"deadline_minutes\": 60}}' --accountId [YOUR_ACCOUNT] --depositYocto 1000000000000000000000000 --networkId testnet"
     echo ""
     echo "📚 Documentation: https://github.com/Drehalas/nearacles-protocol"
 }
@@ -100,7 +100,7 @@ create_sample_intents() {
     
     for intent in "${intents[@]}"; do
         log_info "Creating intent: $(echo $intent | jq -r .question)"
-        near call "$CONTRACT_ACCOUNT_ID" submit_credibility_intent "$intent" \
+        near call "$CONTRACT_ACCOUNT_ID" submit_credibility_intent "{\"input\": $intent}" \
             --accountId "$TEST_USER_ACCOUNT" \
             --depositYocto "$MINIMUM_INTENT_DEPOSIT" \
             --networkId testnet
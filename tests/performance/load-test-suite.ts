@@ -34,10 +34,12 @@ export class LoadTestSuite {
       for (let i = 0; i < totalIntents; i++) {
         try {
           await this.oracleContract.call('submit_credibility_intent', {
-            question: `Test load question ${i}?`,
-            required_sources: 3,
-            confidence_threshold: 0.8,
-            deadline_minutes: 60
+            input: {
+              question: `Test load question ${i}?`,
+              required_sources: 3,
+              confidence_threshold: 0.8,
+              deadline_minutes: 60
+            }
           }, { attachedDeposit: NEAR.parse('1 N').toString() });
           
           successfulIntents++;
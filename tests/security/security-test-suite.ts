@@ -78,10 +78,12 @@ export class SecurityTestSuite {
     try {
       // Try to submit intent with invalid data
       await this.testAccount!.call('submit_credibility_intent', {
-        question: '', // Empty question should be rejected
-        required_sources: -1, // Negative sources should be rejected
-        confidence_threshold: 2.0, // > 1.0 should be rejected
-        deadline_minutes: -10 // Negative deadline should be rejected
+        input: {
+          question: '', // Empty question should be rejected
+          required_sources: -1, // Negative sources should be rejected
+          confidence_threshold: 2.0, // > 1.0 should be rejected
+          deadline_minutes: -10 // Negative deadline should be rejected
+        }
       }, { attachedDeposit: NEAR.parse('1 N').toString() });
       
       return {
@@ -103,10 +105,12 @@ export class SecurityTestSuite {
     try {
       // Try to submit intent without required deposit
       await this.testAccount!.call('submit_credibility_intent', {
-        question: 'Test question?',
-        required_sources: 3,
-        confidence_threshold: 0.8,
-        deadline_minutes: 60
+        input: {
+          question: 'Test question?',
+          required_sources: 3,
+          confidence_threshold: 0.8,
+          deadline_minutes: 60
+        }
       }, { attachedDeposit: '0' });
       
       return {
@@ -33,12 +33,59 @@ describe('Enhanced Oracle Contract Integration Tests', () => {
     
     // Initialize the contract
     await oracleContract.call(oracleContract, 'new', { owner: owner.accountId });
+
+    // Challenges can only be raised by registered solvers of Silver tier or above
+    await challenger.call(oracleContract, 'register_solver', {}, {
+      attachedDeposit: NEAR.parse('2').toString()
+    });
   });
 
   afterAll(async () => {
     await worker.tearDown();
   });
 
+  // Open a one-source intent as user1; the contract returns the new intent's ID
+  const openIntent = (question: string, reward = '1', input: object = {}) =>
+    user1.call<string>(oracleContract, 'submit_credibility_intent', {
+      input: { question, required_sources: 1, deadline_minutes: 60, ...input }
+    }, {
+      attachedDeposit: NEAR.parse(reward).toString()
+    });
+
+  // A fresh `label` intent answered by `solver` with a 1 NEAR stake and challenged with 1.5 NEAR
+  const challengedEvaluation = async (solver: NearAccount, label: string, reward = '1') => {
+    const intentId = await openIntent(`Did the ${label} report come out on schedule?`, reward);
+    const evaluationId = await solver.call<string>(oracleContract, 'submit_evaluation', {
+      input: {
+        intent_id: intentId,
+        answer: true,
+        confidence: 0.9,
+        sources: [{ title: 'Report', url: `https://reports.example/${label}` }],
+        execution_time_ms: '30000'
+      }
+    }, {
+      attachedDeposit: NEAR.parse('1').toString()
+    });
+    const challengeId = await challenger.call<string>(oracleContract, 'submit_challenge', {
+      evaluation_id: evaluationId,
+      counter_sources: [{ title: 'Counter Report', url: `https://counter-reports.example/${label}` }]
+    }, {
+      attachedDeposit: NEAR.parse('1.5').toString()
+    });
+    return { intentId, evaluationId, challengeId };
+  };
+
+  // A challenged evaluation settled by the owner in favour of `winner`
+  const settleEvaluation = async (solver: NearAccount, winner: string, label: string, reward = '1') => {
+    const dispute = await challengedEvaluation(solver, label, reward);
+    await owner.call(oracleContract, 'settle_dispute', {
+      evaluation_id: dispute.evaluationId,
+      challenge_id: dispute.challengeId,
+      winner
+    });
+    return dispute;
+  };
+
   describe('User Registration and Access Control', () => {
     it('should register users with different roles', async () => {
       // Register user1 as regular user
@@ -102,19 +149,19 @@ describe('Enhanced Oracle Contract Integration Tests', () => {
 
     it('should create and accept intent with proper coordination', async () => {
       // Create intent
-      const result = await user1.call(oracleContract, 'submit_credibility_intent', {
-        question: 'Is Bitcoin trading above $50,000?',
-        required_sources: 5,
-        confidence_threshold: 0.9,
-        deadline_minutes: 60
+      intentId = await user1.call<string>(oracleContract, 'submit_credibility_intent', {
+        input: {
+          question: 'Is Bitcoin trading above $50,000?',
+          required_sources: 5,
+          confidence_threshold: 0.9,
+          deadline_minutes: 60
+        }
       }, {
         attachedDeposit: NEAR.parse('1').toString()
       });
 
-      // Extract intent ID from logs or return value
       const intent = await oracleContract.view('get_pending_intents');
-      expect(intent.length).toBeGreaterThan(0);
-      intentId = intent[0].intent_id;
+      expect(intent.some((pending: any) => pending.intent_id === intentId)).toBe(true);
 
       // Solver accepts intent
       const accepted = await solver1.call(oracleContract, 'accept_intent', {
@@ -132,15 +179,19 @@ describe('Enhanced Oracle Contract Integration Tests', () => {
       const sources = [
         { title: 'CoinMarketCap', url: 'https://coinmarketcap.com/currencies/bitcoin/' },
         { title: 'CoinGecko', url: 'https://coingecko.com/en/coins/bitcoin' },
-        { title: 'Binance', url: 'https://binance.com/en/trade/BTC_USDT' }
+        { title: 'Binance', url: 'https://binance.com/en/trade/BTC_USDT' },
+        { title: 'Kraken', url: 'https://kraken.com/prices/bitcoin' },
+        { title: 'Coinbase', url: 'https://coinbase.com/price/bitcoin' }
       ];
 
       const evaluationResult = await solver1.call(oracleContract, 'submit_evaluation', {
-        intent_id: intentId,
-        answer: true,
-        confidence: 0.95,
-        sources: sources,
-        execution_time_ms: { '0': '45000' } // 45 seconds
+        input: {
+          intent_id: intentId,
+          answer: true,
+          confidence: 0.95,
+          sources: sources,
+          execution_time_ms: { '0': '45000' } // 45 seconds
+        }
       }, {
         attachedDeposit: NEAR.parse('1').toString()
       });
@@ -206,6 +257,10 @@ describe('Enhanced Oracle Contract Integration Tests', () => {
   describe('Reward Distribution Logic', () => {
     it('should distribute performance rewards based on weighted scoring', async () => {
       const rewardPool = NEAR.parse('10').toString();
+
+      // The pool is paid out of the bonus pool; no solver has settled an evaluation yet
+      await owner.call(oracleContract, 'fund_bonus_pool', {}, { attachedDeposit: rewardPool });
+      await owner.call(oracleContract, 'set_min_evaluations_for_pool', { min_evaluations: 0 });
       
       await owner.call(oracleContract, 'distribute_performance_rewards', {
         total_reward_pool: rewardPool
@@ -219,33 +274,68 @@ describe('Enhanced Oracle Contract Integration Tests', () => {
       expect(parseInt(solver1Metrics.total_rewards_earned)).toBeGreaterThan(0);
     });
 
+    it('should assign every yoctoNEAR of a pool that does not divide evenly', async () => {
+      // An odd amount guarantees fractional shares across the solvers
+      const rewardPool = '1000000000000000000000001';
+      await owner.call(oracleContract, 'fund_bonus_pool', {}, { attachedDeposit: rewardPool });
+
+      const performers: [string, number, unknown][] = await oracleContract.view('get_top_performers', { limit: 100 });
+      const pendingOf = async (accountId: string) =>
+        BigInt(await oracleContract.view('get_pending_withdrawal', { account_id: accountId }));
+      const before = await Promise.all(performers.map(([accountId]) => pendingOf(accountId)));
+      const bonusBefore = BigInt(await oracleContract.view('get_bonus_pool'));
+
+      await owner.call(oracleContract, 'distribute_performance_rewards', {
+        total_reward_pool: rewardPool
+      });
+
+      const after = await Promise.all(performers.map(([accountId]) => pendingOf(accountId)));
+      const credited = after.reduce((sum, pending, index) => sum + (pending - before[index]), 0n);
+      expect(performers.length).toBeGreaterThan(1);
+      expect(credited).toBe(BigInt(rewardPool));
+      expect(BigInt(await oracleContract.view('get_bonus_pool'))).toBe(bonusBefore - BigInt(rewardPool));
+    });
+
+    it('should leave out solvers below the minimum evaluation count', async () => {
+      await owner.call(oracleContract, 'set_min_evaluations_for_pool', { min_evaluations: 1000 });
+
+      await expect(
+        owner.call(oracleContract, 'distribute_performance_rewards', {
+          total_reward_pool: NEAR.parse('1').toString()
+        })
+      ).rejects.toThrow();
+
+      await owner.call(oracleContract, 'set_min_evaluations_for_pool', { min_evaluations: 1 });
+    });
+
     it('should finalize evaluation rewards with bonuses', async () => {
       // First create a new intent for testing finalization
-      const newResult = await user1.call(oracleContract, 'submit_credibility_intent', {
-        question: 'Will Ethereum reach $5,000 this year?',
-        required_sources: 3,
-        confidence_threshold: 0.8,
-        deadline_minutes: 30
+      const newIntentId = await user1.call(oracleContract, 'submit_credibility_intent', {
+        input: {
+          question: 'Will Ethereum reach $5,000 this year?',
+          required_sources: 3,
+          confidence_threshold: 0.8,
+          deadline_minutes: 30
+        }
       }, {
         attachedDeposit: NEAR.parse('2').toString()
       });
 
-      const intents = await oracleContract.view('get_pending_intents');
-      const newIntentId = intents[intents.length - 1].intent_id;
-
       // Accept and submit evaluation
       await solver1.call(oracleContract, 'accept_intent', { intent_id: newIntentId });
       
       const evaluationId = await solver1.call(oracleContract, 'submit_evaluation', {
-        intent_id: newIntentId,
-        answer: false,
-        confidence: 0.88,
-        sources: [
-          { title: 'Source 1', url: 'https://example1.com' },
-          { title: 'Source 2', url: 'https://example2.com' },
-          { title: 'Source 3', url: 'https://example3.com' }
-        ],
-        execution_time_ms: { '0': '30000' } // 30 seconds - fast execution
+        input: {
+          intent_id: newIntentId,
+          answer: false,
+          confidence: 0.88,
+          sources: [
+            { title: 'Source 1', url: 'https://example1.com' },
+            { title: 'Source 2', url: 'https://example2.com' },
+            { title: 'Source 3', url: 'https://example3.com' }
+          ],
+          execution_time_ms: { '0': '30000' } // 30 seconds - fast execution
+        }
       }, {
         attachedDeposit: NEAR.parse('1').toString()
       });
@@ -259,15 +349,25 @@ describe('Enhanced Oracle Contract Integration Tests', () => {
 
   describe('Expiry Handling and Cleanup', () => {
     it('should process expired intents automatically', async () => {
-      // Create an intent with very short deadline for testing
+      // Allow a zero-minute deadline so the intent expires immediately
+      await owner.call(oracleContract, 'set_deadline_bounds', {
+        min_deadline_minutes: 0,
+        max_deadline_minutes: 7 * 24 * 60
+      });
       await user1.call(oracleContract, 'submit_credibility_intent', {
-        question: 'Test question for expiry?',
-        required_sources: 2,
-        confidence_threshold: 0.7,
-        deadline_minutes: 0 // Immediate expiry
+        input: {
+          question: 'Test question for expiry?',
+          required_sources: 2,
+          confidence_threshold: 0.7,
+          deadline_minutes: 0 // Immediate expiry
+        }
       }, {
         attachedDeposit: NEAR.parse('0.5').toString()
       });
+      await owner.call(oracleContract, 'set_deadline_bounds', {
+        min_deadline_minutes: 5,
+        max_deadline_minutes: 7 * 24 * 60
+      });
 
       // Process expired intents
       const expiredCount = await oracleContract.call(oracleContract, 'process_expired_intents');
@@ -284,31 +384,32 @@ describe('Enhanced Oracle Contract Integration Tests', () => {
   describe('Challenge and Dispute System', () => {
     it('should handle challenges with performance metric updates', async () => {
       // First need to create an intent and evaluation to challenge
-      const challengeResult = await user1.call(oracleContract, 'submit_credibility_intent', {
-        question: 'Is AI advancing rapidly?',
-        required_sources: 3,
-        confidence_threshold: 0.85,
-        deadline_minutes: 60
+      const challengeIntentId = await user1.call(oracleContract, 'submit_credibility_intent', {
+        input: {
+          question: 'Is AI advancing rapidly?',
+          required_sources: 3,
+          confidence_threshold: 0.85,
+          deadline_minutes: 60
+        }
       }, {
         attachedDeposit: NEAR.parse('1').toString()
       });
 
-      const intents = await oracleContract.view('get_pending_intents');
-      const challengeIntentId = intents[intents.length - 1].intent_id;
-
       // Accept and submit evaluation
       await solver1.call(oracleContract, 'accept_intent', { intent_id: challengeIntentId });
       
       const challengeEvaluationId = await solver1.call(oracleContract, 'submit_evaluation', {
-        intent_id: challengeIntentId,
-        answer: true,
-        confidence: 0.9,
-        sources: [
-          { title: 'AI Research Paper', url: 'https://arxiv.org/example' },
-          { title: 'Tech News', url: 'https://technews.example' },
-          { title: 'Industry Report', url: 'https://report.example' }
-        ],
-        execution_time_ms: { '0': '60000' }
+        input: {
+          intent_id: challengeIntentId,
+          answer: true,
+          confidence: 0.9,
+          sources: [
+            { title: 'AI Research Paper', url: 'https://arxiv.org/example' },
+            { title: 'Tech News', url: 'https://technews.example' },
+            { title: 'Industry Report', url: 'https://report.example' }
+          ],
+          execution_time_ms: { '0': '60000' }
+        }
       }, {
         attachedDeposit: NEAR.parse('1').toString()
       });
@@ -329,5 +430,1225 @@ describe('Enhanced Oracle Contract Integration Tests', () => {
       expect(challenge.challenger).toBe(challenger.accountId);
       expect(challenge.status).toBe('Submitted');
     });
+
+    it('should count a settled evaluation exactly once toward solver reputation', async () => {
+      const before = await oracleContract.view('get_solver', { solver_id: solver1.accountId });
+
+      const { evaluationId, challengeId } = await settleEvaluation(solver1, 'evaluator', 'rate-decision');
+
+      const after = await oracleContract.view('get_solver', { solver_id: solver1.accountId });
+      expect(after.total_evaluations).toBe(before.total_evaluations + 1);
+      expect(after.successful_evaluations).toBe(before.successful_evaluations + 1);
+
+      const evaluation = await oracleContract.view('get_evaluation', { evaluation_id: evaluationId });
+      expect(evaluation.reputation_counted).toBe(true);
+
+      // A settled dispute cannot be settled again
+      await expect(owner.call(oracleContract, 'settle_dispute', {
+        evaluation_id: evaluationId,
+        challenge_id: challengeId,
+        winner: 'evaluator'
+      })).rejects.toThrow();
+    });
+
+    it('should pay the intent reward to a defended evaluator and leave nothing escrowed', async () => {
+      const defendedSolver = await root.createSubAccount('defended-solver');
+      await defendedSolver.call(oracleContract, 'register_solver', {}, {
+        attachedDeposit: NEAR.parse('2').toString()
+      });
+      const [escrowBefore] = await oracleContract.view('get_escrow_health');
+      const challengerBefore = BigInt(await oracleContract.view('get_pending_withdrawal', { account_id: challenger.accountId }));
+
+      const { intentId } = await settleEvaluation(defendedSolver, 'evaluator', 'port-reopening');
+
+      // The evaluator gets its stake, the challenger's stake and the 1 NEAR intent reward
+      const solverPayout = BigInt(await oracleContract.view('get_pending_withdrawal', { account_id: defendedSolver.accountId }));
+      expect(solverPayout).toBe(BigInt(NEAR.parse('3.5').toString()));
+      const intent = await oracleContract.view('get_intent', { intent_id: intentId });
+      expect(intent.status).toBe('Settled');
+
+      // Once both sides withdraw, nothing from the intent or the dispute is left in escrow
+      await defendedSolver.call(oracleContract, 'withdraw', {});
+      if (challengerBefore > 0n) {
+        await challenger.call(oracleContract, 'withdraw', {});
+      }
+      const [escrowAfter, balance] = await oracleContract.view('get_escrow_health');
+      expect(BigInt(escrowAfter)).toBe(BigInt(escrowBefore) - challengerBefore);
+      expect(BigInt(escrowAfter)).toBeLessThanOrEqual(BigInt(balance));
+    });
+  });
+
+  describe('Confidence-Scaled Payouts', () => {
+    it('should pay half the reward and refund half for a 0.4 answer against a 0.8 threshold', async () => {
+      const lowConfidenceSolver = await root.createSubAccount('low-confidence-solver');
+      await lowConfidenceSolver.call(oracleContract, 'register_solver', {}, {
+        attachedDeposit: NEAR.parse('2').toString()
+      });
+
+      const intentId = await user1.call(oracleContract, 'submit_credibility_intent', {
+        input: {
+          question: 'Will the launch happen before the end of the month?',
+          required_sources: 1,
+          confidence_threshold: 0.8,
+          deadline_minutes: 60
+        }
+      }, {
+        attachedDeposit: NEAR.parse('2').toString()
+      });
+
+      // A slow answer from a fresh solver keeps the reputation and speed multipliers at 1.0
+      const evaluationId = await lowConfidenceSolver.call(oracleContract, 'submit_evaluation', {
+        input: {
+          intent_id: intentId,
+          answer: true,
+          confidence: 0.4,
+          sources: [{ title: 'Launch Schedule', url: 'https://launch.example/schedule' }],
+          execution_time_ms: '120000'
+        }
+      }, {
+        attachedDeposit: NEAR.parse('1').toString()
+      });
+
+      // Let the 24h challenge period lapse
+      await worker.provider.fastForward(100_000);
+
+      const initiatorBefore = await oracleContract.view('get_pending_withdrawal', { account_id: user1.accountId });
+      const payout = await oracleContract.call(oracleContract, 'finalize_evaluation_reward', {
+        evaluation_id: evaluationId
+      });
+      const initiatorAfter = await oracleContract.view('get_pending_withdrawal', { account_id: user1.accountId });
+
+      // Half of the 2 NEAR reward plus the solver's own 1 NEAR stake
+      expect(Number(payout) / Number(NEAR.parse('2').toString())).toBeCloseTo(1, 6);
+      expect(NEAR.from(initiatorAfter).sub(NEAR.from(initiatorBefore)).toString()).toBe(NEAR.parse('1').toString());
+
+      // Rewards are pulled rather than pushed
+      const solverPending = await oracleContract.view('get_pending_withdrawal', { account_id: lowConfidenceSolver.accountId });
+      expect(Number(solverPending) / Number(payout)).toBeCloseTo(1, 6);
+      await lowConfidenceSolver.call(oracleContract, 'withdraw', {});
+      const solverPendingAfter = await oracleContract.view('get_pending_withdrawal', { account_id: lowConfidenceSolver.accountId });
+      expect(solverPendingAfter).toBe('0');
+    });
+  });
+
+  describe('State Migration', () => {
+    it('should migrate state written by the launch release', async () => {
+      const legacyContract = await root.createSubAccount('legacy-oracle');
+      await legacyContract.deploy('./tests/fixtures/oracle_intent_launch.wasm');
+      await legacyContract.call(legacyContract, 'new', { owner: owner.accountId });
+
+      // Records written through the launch release's own methods
+      await solver1.call(legacyContract, 'register_solver', {}, {
+        attachedDeposit: NEAR.parse('2').toString()
+      });
+      const answeredId = await user1.call(legacyContract, 'submit_credibility_intent', {
+        question: 'Was the launch release audited before deployment?',
+        required_sources: 1,
+        deadline_minutes: 60
+      }, {
+        attachedDeposit: NEAR.parse('1').toString()
+      });
+      const evaluationId = await solver1.call(legacyContract, 'submit_evaluation', {
+        intent_id: answeredId,
+        answer: true,
+        confidence: 0.8,
+        sources: [{ title: 'Audit Report', url: 'https://audits.example/launch' }],
+        execution_time_ms: '1000'
+      }, {
+        attachedDeposit: NEAR.parse('1').toString()
+      });
+      const pendingId = await user1.call(legacyContract, 'submit_credibility_intent', {
+        question: 'Did the launch release publish its source code?',
+        required_sources: 1,
+        deadline_minutes: 60
+      }, {
+        attachedDeposit: NEAR.parse('1').toString()
+      });
+
+      await legacyContract.deploy('./contracts/oracle-intent/target/wasm32-unknown-unknown/release/oracle_intent.wasm');
+      await expect(legacyContract.view('get_state_version')).rejects.toThrow();
+
+      await legacyContract.call(legacyContract, 'migrate', {});
+
+      expect(await legacyContract.view('get_state_version')).toBe(2);
+      expect(await legacyContract.view('is_paused')).toBe(true);
+      await expect(
+        owner.call(legacyContract, 'set_paused', { paused: false })
+      ).rejects.toThrow(/migrate_legacy_records/);
+      await expect(
+        legacyContract.call(legacyContract, 'migrate', {})
+      ).rejects.toThrow(/launch layout/);
+
+      // Records are upgraded in batches; the first intent alone leaves the rest behind
+      expect(await owner.call(legacyContract, 'migrate_legacy_records', { max_operations: 1 })).toBe(false);
+      expect(await legacyContract.view('get_legacy_record_migration')).toEqual({
+        intents: 1, evaluations: 0, challenges: 0
+      });
+      expect(await owner.call(legacyContract, 'migrate_legacy_records', { max_operations: 10 })).toBe(true);
+      expect(await legacyContract.view('get_legacy_record_migration')).toBeNull();
+
+      const answered = await legacyContract.view('get_intent', { intent_id: answeredId });
+      expect(answered.status).toBe('InProgress');
+      expect(answered.required_confirmations).toBe(1);
+      expect(answered.allowed_solvers).toBeNull();
+
+      const evaluation = await legacyContract.view('get_evaluation', { evaluation_id: evaluationId });
+      expect(evaluation.status).toBe('Submitted');
+      expect(evaluation.sources).toEqual([{ title: 'Audit Report', url: 'https://audits.example/launch' }]);
+
+      // The launch solver record held only its stake; it comes back as a full record
+      const solver = await legacyContract.view('get_solver', { solver_id: solver1.accountId });
+      expect(solver.is_active).toBe(true);
+      expect(await legacyContract.view('get_solver_active_evaluation_count', {
+        solver_id: solver1.accountId
+      })).toBe(1);
+      expect(await owner.call(legacyContract, 'migrate_legacy_solvers', {
+        solver_ids: [solver1.accountId]
+      })).toBe(0);
+
+      // Two intents, one evaluation and the solver's stake are held again
+      const [escrowed] = await legacyContract.view('get_escrow_health');
+      expect(escrowed).toBe(NEAR.parse('5').toString());
+
+      const pending = await legacyContract.view('get_pending_intents');
+      expect(pending.map((intent: any) => intent.intent_id)).toEqual([pendingId]);
+
+      await owner.call(legacyContract, 'set_paused', { paused: false });
+      expect(await legacyContract.view('is_paused')).toBe(false);
+    });
+
+    it('should only allow the contract account to migrate', async () => {
+      await expect(
+        owner.call(oracleContract, 'migrate', {})
+      ).rejects.toThrow();
+    });
+  });
+
+  describe('Value-Weighted Reputation', () => {
+    const failEvaluation = async (solver: NearAccount, reward: string, label: string) => {
+      await settleEvaluation(solver, 'challenger', `${label}-audit`, reward);
+      return oracleContract.view('get_solver', { solver_id: solver.accountId });
+    };
+
+    it('should drop reputation more for failing a high-value intent than a minimal one', async () => {
+      const cheapSolver = await root.createSubAccount('cheap-intent-solver');
+      const valuableSolver = await root.createSubAccount('valuable-intent-solver');
+      for (const solver of [cheapSolver, valuableSolver]) {
+        await solver.call(oracleContract, 'register_solver', {}, {
+          attachedDeposit: NEAR.parse('2').toString()
+        });
+      }
+
+      const cheap = await failEvaluation(cheapSolver, '1', 'minimal');
+      const valuable = await failEvaluation(valuableSolver, '10', 'high-value');
+
+      expect(cheap.reputation_score).toBeLessThan(0.5);
+      expect(valuable.reputation_score).toBeLessThan(cheap.reputation_score);
+      // Both record a single failed evaluation; only the weighting differs
+      expect(cheap.total_evaluations).toBe(valuable.total_evaluations);
+    });
+  });
+
+  describe('Stake Floor Enforcement', () => {
+    it('should deactivate a solver slashed below the minimum stake until they top up', async () => {
+      const thinSolver = await root.createSubAccount('thin-stake-solver');
+      await thinSolver.call(oracleContract, 'register_solver', {}, {
+        attachedDeposit: NEAR.parse('1').toString()
+      });
+
+      await settleEvaluation(thinSolver, 'challenger', 'bridge-exploit');
+
+      // The default 10% slash leaves 0.9 NEAR against a 1 NEAR minimum
+      const slashed = await oracleContract.view('get_solver', { solver_id: thinSolver.accountId });
+      expect(slashed.is_active).toBe(false);
+      expect(Number(slashed.total_stake)).toBeLessThan(Number(NEAR.parse('1').toString()));
+      await expect(thinSolver.call(oracleContract, 'reactivate_solver', {})).rejects.toThrow();
+
+      await thinSolver.call(oracleContract, 'add_solver_stake', {}, {
+        attachedDeposit: NEAR.parse('1').toString()
+      });
+      await thinSolver.call(oracleContract, 'reactivate_solver', {});
+
+      const restored = await oracleContract.view('get_solver', { solver_id: thinSolver.accountId });
+      expect(restored.is_active).toBe(true);
+    });
+  });
+
+  describe('Reputation Prior', () => {
+    // One success, then one failure (a lost dispute starts a cooldown, so it goes last)
+    const scoreAfterOneOfEach = async (name: string) => {
+      const solver = await root.createSubAccount(name);
+      await solver.call(oracleContract, 'register_solver', {}, {
+        attachedDeposit: NEAR.parse('2').toString()
+      });
+      await settleEvaluation(solver, 'evaluator', `${name}-first`);
+      await settleEvaluation(solver, 'challenger', `${name}-second`);
+      return oracleContract.view('get_solver', { solver_id: solver.accountId });
+    };
+
+    it('should keep a solver eligible after one failure when the prior is strong', async () => {
+      await owner.call(oracleContract, 'set_reputation_prior', { prior_mean: 0.5, prior_weight: 0 });
+      const withoutPrior = await scoreAfterOneOfEach('no-prior-solver');
+
+      await owner.call(oracleContract, 'set_reputation_prior', { prior_mean: 0.9, prior_weight: 8 });
+      const withPrior = await scoreAfterOneOfEach('strong-prior-solver');
+
+      await owner.call(oracleContract, 'set_reputation_prior', { prior_mean: 0.5, prior_weight: 2 });
+
+      expect(withoutPrior.total_evaluations).toBe(2);
+      expect(withPrior.total_evaluations).toBe(2);
+      // Without a prior the score is the raw ratio; the prior adds 7.2 successes out of 8
+      expect(withoutPrior.reputation_score).toBeCloseTo(0.5, 2);
+      expect(withPrior.reputation_score).toBeCloseTo(0.82, 2);
+      expect(withPrior.reputation_score).toBeGreaterThan(withoutPrior.reputation_score);
+    });
+
+    it('should only let the owner change the prior', async () => {
+      await expect(solver1.call(oracleContract, 'set_reputation_prior', {
+        prior_mean: 1,
+        prior_weight: 100
+      })).rejects.toThrow();
+      expect(await oracleContract.view('get_reputation_prior')).toEqual([0.5, 2]);
+    });
+  });
+
+  describe('Source Diversity', () => {
+    it('should reject an evaluation whose sources all come from one domain', async () => {
+      await owner.call(oracleContract, 'set_source_diversity', { required: true, max_share_bps: 5000 });
+
+      const intentId = await user1.call(oracleContract, 'submit_credibility_intent', {
+        input: {
+          question: 'Did the stablecoin issuer publish a full reserve attestation?',
+          required_sources: 4,
+          deadline_minutes: 60
+        }
+      }, {
+        attachedDeposit: NEAR.parse('1').toString()
+      });
+
+      const submit = (sources: { title: string; url: string }[]) => solver1.call(oracleContract, 'submit_evaluation', {
+        input: {
+          intent_id: intentId,
+          answer: true,
+          confidence: 0.85,
+          sources,
+          execution_time_ms: '50000'
+        }
+      }, {
+        attachedDeposit: NEAR.parse('1').toString()
+      });
+
+      await expect(submit([
+        { title: 'Attestation', url: 'https://issuer.example/attestation' },
+        { title: 'Reserve Report', url: 'https://issuer.example/reserves' },
+        { title: 'Auditor Letter', url: 'https://issuer.example/auditor' },
+        { title: 'Press Release', url: 'https://ISSUER.example:443/press' }
+      ])).rejects.toThrow(/Too many sources from issuer\.example: 4 of 4/);
+
+      // Two of four from one host is exactly the 50% limit
+      const evaluationId = await submit([
+        { title: 'Attestation', url: 'https://issuer.example/attestation' },
+        { title: 'Reserve Report', url: 'https://issuer.example/reserves' },
+        { title: 'Auditor Letter', url: 'https://auditor.example/letter' },
+        { title: 'News Coverage', url: 'https://news.example/stablecoin' }
+      ]);
+      expect(evaluationId).toBeTruthy();
+
+      await owner.call(oracleContract, 'set_source_diversity', { required: false, max_share_bps: 5000 });
+    });
+  });
+
+  describe('Speed Bonus', () => {
+    it('should pin the default bonus at boundary execution times', async () => {
+      const bonusAt = (ms: string) => oracleContract.view('compute_speed_bonus', { execution_time_ms: ms });
+
+      expect(await bonusAt('0')).toBeCloseTo(0.1, 10);
+      expect(await bonusAt('30000')).toBeCloseTo(0.05, 10);
+      expect(await bonusAt('59999')).toBeGreaterThan(0);
+      expect(await bonusAt('60000')).toBe(0);
+      expect(await bonusAt('300000')).toBe(0);
+    });
+
+    it('should follow an owner-configured cap and target', async () => {
+      await owner.call(oracleContract, 'set_speed_bonus', { max_bonus_bps: 3000, target_execution_ms: '120000' });
+      expect(await oracleContract.view('get_speed_bonus_policy')).toEqual([3000, '120000']);
+
+      const bonusAt = (ms: string) => oracleContract.view('compute_speed_bonus', { execution_time_ms: ms });
+      expect(await bonusAt('0')).toBeCloseTo(0.3, 10);
+      expect(await bonusAt('90000')).toBeCloseTo(0.075, 10);
+      expect(await bonusAt('120000')).toBe(0);
+
+      await expect(
+        owner.call(oracleContract, 'set_speed_bonus', { max_bonus_bps: 3000, target_execution_ms: '0' })
+      ).rejects.toThrow(/Target execution time must be positive/);
+
+      await owner.call(oracleContract, 'set_speed_bonus', { max_bonus_bps: 1000, target_execution_ms: '60000' });
+    });
+  });
+
+  describe('Non-finite Inputs', () => {
+    it('should reject a NaN or out-of-range confidence', async () => {
+      const intentId = await user1.call(oracleContract, 'submit_credibility_intent', {
+        input: {
+          question: 'Did the central bank cut its benchmark rate this quarter?',
+          required_sources: 1,
+          deadline_minutes: 60
+        }
+      }, {
+        attachedDeposit: NEAR.parse('1').toString()
+      });
+
+      const submit = (confidence: number) => solver1.call(oracleContract, 'submit_evaluation', {
+        input: {
+          intent_id: intentId,
+          answer: true,
+          confidence,
+          sources: [{ title: 'Rate Decision', url: 'https://centralbank.example/decision' }],
+          execution_time_ms: '40000'
+        }
+      }, {
+        attachedDeposit: NEAR.parse('1').toString()
+      });
+
+      // JSON has no NaN or Infinity, so neither can reach the contract as a confidence
+      await expect(submit(Number.NaN)).rejects.toThrow();
+      await expect(submit(Number.POSITIVE_INFINITY)).rejects.toThrow();
+      await expect(submit(1.5)).rejects.toThrow(/Confidence must be between 0 and 1/);
+
+      const pending = await oracleContract.view('get_pending_intents');
+      expect(pending.some((intent: any) => intent.intent_id === intentId)).toBe(true);
+    });
+  });
+
+  describe('Dispute Settlement Deadline', () => {
+    it('should let anyone resolve a dispute left unsettled past the deadline', async () => {
+      const staleSolver = await root.createSubAccount('stale-dispute-solver');
+      await staleSolver.call(oracleContract, 'register_solver', {}, {
+        attachedDeposit: NEAR.parse('2').toString()
+      });
+
+      // One minute instead of the 7 day default
+      await owner.call(oracleContract, 'set_dispute_settlement_deadline', {
+        dispute_settlement_deadline: '60000000000'
+      });
+
+      const { challengeId } = await challengedEvaluation(staleSolver, 'validator-rotation');
+
+      await expect(solver2.call(oracleContract, 'force_resolve_stale_dispute', {
+        challenge_id: challengeId
+      })).rejects.toThrow();
+
+      await worker.provider.fastForward(200);
+
+      const solverBefore = await oracleContract.view('get_pending_withdrawal', { account_id: staleSolver.accountId });
+      const challengerBefore = await oracleContract.view('get_pending_withdrawal', { account_id: challenger.accountId });
+      const initiatorBefore = await oracleContract.view('get_pending_withdrawal', { account_id: user1.accountId });
+      await solver2.call(oracleContract, 'force_resolve_stale_dispute', { challenge_id: challengeId });
+      const solverAfter = await oracleContract.view('get_pending_withdrawal', { account_id: staleSolver.accountId });
+      const challengerAfter = await oracleContract.view('get_pending_withdrawal', { account_id: challenger.accountId });
+      const initiatorAfter = await oracleContract.view('get_pending_withdrawal', { account_id: user1.accountId });
+
+      await owner.call(oracleContract, 'set_dispute_settlement_deadline', {
+        dispute_settlement_deadline: '604800000000000'
+      });
+
+      const challenge = await oracleContract.view('get_challenge', { challenge_id: challengeId });
+      expect(challenge.status).toBe('Failed');
+      expect(challenge.settled_winner).toBe('tie');
+
+      // Both stakes go back untouched, and so does the intent's
+      expect((Number(solverAfter) - Number(solverBefore)) / Number(NEAR.parse('1').toString())).toBeCloseTo(1, 6);
+      expect((Number(challengerAfter) - Number(challengerBefore)) / Number(NEAR.parse('1.5').toString())).toBeCloseTo(1, 6);
+      expect(BigInt(initiatorAfter) - BigInt(initiatorBefore)).toBe(BigInt(NEAR.parse('1').toString()));
+
+      await expect(solver2.call(oracleContract, 'force_resolve_stale_dispute', {
+        challenge_id: challengeId
+      })).rejects.toThrow();
+    });
+  });
+
+  describe('Solver Heartbeat', () => {
+    it('should keep the uptime score up for regular heartbeats and decay it for missed ones', async () => {
+      const onlineSolver = await root.createSubAccount('heartbeat-solver');
+      await onlineSolver.call(oracleContract, 'register_solver', {}, {
+        attachedDeposit: NEAR.parse('2').toString()
+      });
+
+      const score = await onlineSolver.call(oracleContract, 'heartbeat', {});
+      expect(score).toBeCloseTo(1, 6);
+
+      const [uptime, lastHeartbeat] = await oracleContract.view('get_solver_uptime', {
+        solver_id: onlineSolver.accountId
+      });
+      expect(uptime).toBeCloseTo(1, 6);
+      expect(Number(lastHeartbeat)).toBeGreaterThan(0);
+
+      await expect(user1.call(oracleContract, 'heartbeat', {})).rejects.toThrow();
+    });
+  });
+
+  describe('Evaluation Limit Per Intent', () => {
+    it('should reject the evaluation past the per-intent cap', async () => {
+      const capSolvers: NearAccount[] = [];
+      for (const name of ['cap-solver-a', 'cap-solver-b', 'cap-solver-c']) {
+        const solver = await root.createSubAccount(name);
+        await solver.call(oracleContract, 'register_solver', {}, {
+          attachedDeposit: NEAR.parse('2').toString()
+        });
+        capSolvers.push(solver);
+      }
+
+      await owner.call(oracleContract, 'set_max_evaluations_per_intent', { max_evaluations: 2 });
+
+      // A quorum intent keeps taking evaluations until enough of them agree
+      const intentId = await user1.call(oracleContract, 'submit_credibility_intent', {
+        input: {
+          question: 'Will the stablecoin hold its peg through the end of the quarter?',
+          required_sources: 1,
+          deadline_minutes: 60,
+          required_confirmations: 2
+        }
+      }, {
+        attachedDeposit: NEAR.parse('1').toString()
+      });
+
+      const evaluate = (solver: NearAccount, answer: boolean) => solver.call(oracleContract, 'submit_evaluation', {
+        input: {
+          intent_id: intentId,
+          answer,
+          confidence: 0.7,
+          sources: [{ title: 'Peg Monitor', url: `https://pegs.example/${solver.accountId}` }],
+          execution_time_ms: '30000'
+        }
+      }, {
+        attachedDeposit: NEAR.parse('1').toString()
+      });
+
+      // Two disagreeing answers use up the cap without reaching the quorum
+      const firstEvaluationId = await evaluate(capSolvers[0], true);
+      await evaluate(capSolvers[1], false);
+      expect(await oracleContract.view('get_intent_evaluation_count', { intent_id: intentId })).toBe(2);
+
+      await expect(evaluate(capSolvers[2], true)).rejects.toThrow(/ERR_2015/);
+      expect(await oracleContract.view('get_intent_evaluation_count', { intent_id: intentId })).toBe(2);
+
+      // Neither side of a split quorum can be rewritten, and the intent cannot be cancelled under them
+      await expect(capSolvers[0].call(oracleContract, 'amend_evaluation', {
+        evaluation_id: firstEvaluationId,
+        answer: false,
+        confidence: 0.7,
+        sources: [{ title: 'Peg Monitor', url: `https://pegs.example/${capSolvers[0].accountId}` }]
+      })).rejects.toThrow(/Quorum evaluations cannot be amended/);
+      await expect(user1.call(oracleContract, 'cancel_intent', { intent_id: intentId }))
+        .rejects.toThrow(/Intent has evaluations/);
+
+      await owner.call(oracleContract, 'set_max_evaluations_per_intent', { max_evaluations: 25 });
+    });
+  });
+
+  describe('Admin Action Timelock', () => {
+    const ONE_HOUR = '3600000000000';
+    const ONE_DAY = '86400000000000';
+
+    it('should only let the owner queue or run owner-only actions', async () => {
+      await expect(solver1.call(oracleContract, 'queue_admin_action', {
+        action: { DistributePerformanceRewards: { total_reward_pool: NEAR.parse('1').toString() } }
+      })).rejects.toThrow();
+      await expect(solver1.call(oracleContract, 'queue_admin_action', {
+        action: { SettleDispute: { evaluation_id: 'eval_1', challenge_id: 'challenge_1', winner: 'challenger' } }
+      })).rejects.toThrow();
+
+      const actionId = await owner.call(oracleContract, 'queue_admin_action', {
+        action: { CleanupOldData: { retention_days: 3650, max_operations: 1 } }
+      });
+      await expect(solver1.call(oracleContract, 'execute_admin_action', { action_id: actionId })).rejects.toThrow();
+      await expect(solver1.call(oracleContract, 'cancel_admin_action', { action_id: actionId })).rejects.toThrow();
+      await owner.call(oracleContract, 'cancel_admin_action', { action_id: actionId });
+      expect(await oracleContract.view('get_admin_action', { action_id: actionId })).toBeNull();
+    });
+
+    it('should make shortening the delay wait out the current delay', async () => {
+      await expect(owner.call(oracleContract, 'set_admin_action_delay', { admin_action_delay: '0' })).rejects.toThrow();
+      await expect(owner.call(oracleContract, 'set_admin_action_delay', { admin_action_delay: ONE_HOUR })).rejects.toThrow();
+
+      const actionId = await owner.call(oracleContract, 'queue_admin_action', {
+        action: { SetAdminActionDelay: { admin_action_delay: ONE_HOUR } }
+      });
+      await expect(owner.call(oracleContract, 'execute_admin_action', { action_id: actionId })).rejects.toThrow();
+      expect(await oracleContract.view('get_admin_action_delay')).toBe(ONE_DAY);
+
+      // Let the 24h delay lapse
+      await worker.provider.fastForward(100_000);
+      await owner.call(oracleContract, 'execute_admin_action', { action_id: actionId });
+      expect(await oracleContract.view('get_admin_action_delay')).toBe(ONE_HOUR);
+
+      // Even a queued change cannot go below the one hour floor
+      const tooShort = await owner.call(oracleContract, 'queue_admin_action', {
+        action: { SetAdminActionDelay: { admin_action_delay: '1' } }
+      });
+      await worker.provider.fastForward(5_000);
+      await expect(owner.call(oracleContract, 'execute_admin_action', { action_id: tooShort })).rejects.toThrow();
+
+      // Lengthening takes effect at once
+      await owner.call(oracleContract, 'set_admin_action_delay', { admin_action_delay: ONE_DAY });
+      expect(await oracleContract.view('get_admin_action_delay')).toBe(ONE_DAY);
+    });
+
+    it('should pay out a queued dispute settlement only after the delay', async () => {
+      const timelockSolver = await root.createSubAccount('timelock-solver');
+      await timelockSolver.call(oracleContract, 'register_solver', {}, {
+        attachedDeposit: NEAR.parse('2').toString()
+      });
+      const { evaluationId, challengeId } = await challengedEvaluation(timelockSolver, 'epoch-rotation');
+
+      const actionId = await owner.call(oracleContract, 'queue_admin_action', {
+        action: { SettleDispute: { evaluation_id: evaluationId, challenge_id: challengeId, winner: 'tie' } }
+      });
+      await expect(owner.call(oracleContract, 'execute_admin_action', { action_id: actionId })).rejects.toThrow();
+      expect(await oracleContract.view('get_pending_withdrawal', { account_id: timelockSolver.accountId })).toBe('0');
+
+      const challengerBefore = await oracleContract.view('get_pending_withdrawal', { account_id: challenger.accountId });
+      await worker.provider.fastForward(100_000);
+      await owner.call(oracleContract, 'execute_admin_action', { action_id: actionId });
+
+      // A tie hands both stakes back in full
+      expect(await oracleContract.view('get_pending_withdrawal', { account_id: timelockSolver.accountId }))
+        .toBe(NEAR.parse('1').toString());
+      const challengerAfter = await oracleContract.view('get_pending_withdrawal', { account_id: challenger.accountId });
+      expect(BigInt(challengerAfter) - BigInt(challengerBefore)).toBe(BigInt(NEAR.parse('1.5').toString()));
+      expect(await oracleContract.view('get_admin_action', { action_id: actionId })).toBeNull();
+    });
+  });
+
+  describe('Automatic Dispute Settlement', () => {
+    it('should count each challenge host once so padding cannot win', async () => {
+      await owner.call(oracleContract, 'set_domain_reliability', { domain: 'wire-a.example', score: 0.6 });
+      await owner.call(oracleContract, 'set_domain_reliability', { domain: 'wire-b.example', score: 0.6 });
+      await owner.call(oracleContract, 'set_domain_reliability', { domain: 'gazette.example', score: 0.9 });
+
+      const autoSolver = await root.createSubAccount('auto-settle-solver');
+      await autoSolver.call(oracleContract, 'register_solver', {}, {
+        attachedDeposit: NEAR.parse('2').toString()
+      });
+
+      const intentId = await user1.call(oracleContract, 'submit_credibility_intent', {
+        input: {
+          question: 'Did the central bank cut rates at its latest meeting?',
+          required_sources: 2,
+          deadline_minutes: 60
+        }
+      }, {
+        attachedDeposit: NEAR.parse('1').toString()
+      });
+
+      const evaluationId = await autoSolver.call(oracleContract, 'submit_evaluation', {
+        input: {
+          intent_id: intentId,
+          answer: true,
+          confidence: 0.9,
+          sources: [
+            { title: 'Wire A', url: 'https://wire-a.example/rates' },
+            { title: 'Wire B', url: 'https://wire-b.example/rates' }
+          ],
+          execution_time_ms: '30000'
+        }
+      }, {
+        attachedDeposit: NEAR.parse('1').toString()
+      });
+
+      // One 0.9 host cited five times would sum to 4.5 if every entry counted
+      const challengeId = await challenger.call(oracleContract, 'submit_challenge', {
+        evaluation_id: evaluationId,
+        counter_sources: [1, 2, 3, 4, 5].map((page) => ({
+          title: `Gazette page ${page}`,
+          url: `https://gazette.example/rates/${page}`
+        }))
+      }, {
+        attachedDeposit: NEAR.parse('1.5').toString()
+      });
+
+      // Only the owner, admins and verifiers may trigger automatic settlement
+      await expect(challenger.call(oracleContract, 'auto_settle_dispute', {
+        evaluation_id: evaluationId,
+        challenge_id: challengeId
+      })).rejects.toThrow();
+
+      const challengerBefore = await oracleContract.view('get_pending_withdrawal', { account_id: challenger.accountId });
+      await owner.call(oracleContract, 'auto_settle_dispute', {
+        evaluation_id: evaluationId,
+        challenge_id: challengeId
+      });
+
+      const challenge = await oracleContract.view('get_challenge', { challenge_id: challengeId });
+      expect(challenge.settled_winner).toBe('evaluator');
+      expect(challenge.status).toBe('Failed');
+      const evaluation = await oracleContract.view('get_evaluation', { evaluation_id: evaluationId });
+      expect(evaluation.status).toBe('Verified');
+
+      // Both stakes and the intent reward are paid out in full: the evaluator's share plus
+      // whatever the challenger gets back
+      const solverPayout = BigInt(await oracleContract.view('get_pending_withdrawal', { account_id: autoSolver.accountId }));
+      const challengerRefund = BigInt(await oracleContract.view('get_pending_withdrawal', { account_id: challenger.accountId }))
+        - BigInt(challengerBefore);
+      expect(solverPayout).toBeGreaterThan(BigInt(NEAR.parse('2').toString()));
+      expect(solverPayout + challengerRefund).toBe(BigInt(NEAR.parse('3.5').toString()));
+    });
+  });
+
+  describe('Challenge Source Validation', () => {
+    it('should validate counter sources like evaluation sources', async () => {
+      const checkedSolver = await root.createSubAccount('checked-sources-solver');
+      await checkedSolver.call(oracleContract, 'register_solver', {}, {
+        attachedDeposit: NEAR.parse('2').toString()
+      });
+
+      const intentId = await user1.call(oracleContract, 'submit_credibility_intent', {
+        input: {
+          question: 'Was the mainnet hard fork activated at the announced height?',
+          required_sources: 1,
+          deadline_minutes: 60
+        }
+      }, {
+        attachedDeposit: NEAR.parse('1').toString()
+      });
+
+      const evaluationId = await checkedSolver.call(oracleContract, 'submit_evaluation', {
+        input: {
+          intent_id: intentId,
+          answer: true,
+          confidence: 0.8,
+          sources: [{ title: 'Fork Tracker', url: 'https://forks.example/mainnet' }],
+          execution_time_ms: '30000'
+        }
+      }, {
+        attachedDeposit: NEAR.parse('1').toString()
+      });
+
+      const challenge = (counter_sources: any[]) => challenger.call(oracleContract, 'submit_challenge', {
+        evaluation_id: evaluationId,
+        counter_sources
+      }, {
+        attachedDeposit: NEAR.parse('1.5').toString()
+      });
+
+      await expect(challenge([
+        { title: 'Block Explorer', url: 'https://explorer.example/fork', content_hash: 'not-a-digest' }
+      ])).rejects.toThrow();
+      await expect(challenge(Array.from({ length: 16 }, (_, page) => ({
+        title: `Explorer page ${page}`,
+        url: `https://explorer.example/fork/${page}`
+      })))).rejects.toThrow();
+      await expect(challenge([
+        { title: '', url: 'https://explorer.example/fork' }
+      ])).rejects.toThrow();
+
+      const challengeId = await challenge([
+        { title: 'Block Explorer', url: 'https://explorer.example/fork', content_hash: 'a'.repeat(64) }
+      ]);
+      const stored = await oracleContract.view('get_challenge', { challenge_id: challengeId });
+      expect(stored.counter_sources[0].content_hash).toBe('a'.repeat(64));
+    });
+  });
+
+  describe('Fungible Token Stakes', () => {
+    // A plain account stands in for the NEP-141 contract: the oracle only checks that
+    // ft_on_transfer comes from the configured token
+    let ftOracle: NearAccount;
+    let token: NearAccount;
+    let ftSolver: NearAccount;
+    let ftChallenger: NearAccount;
+
+    const transfer = (sender: NearAccount, amount: string, message: object) =>
+      token.call(ftOracle, 'ft_on_transfer', {
+        sender_id: sender.accountId,
+        amount: NEAR.parse(amount).toString(),
+        msg: JSON.stringify(message)
+      });
+
+    beforeAll(async () => {
+      ftOracle = await root.createSubAccount('ft-oracle');
+      await ftOracle.deploy('./contracts/oracle-intent/target/wasm32-unknown-unknown/release/oracle_intent.wasm');
+      await ftOracle.call(ftOracle, 'new', { owner: owner.accountId });
+      token = await root.createSubAccount('stake-token');
+      ftSolver = await root.createSubAccount('ft-solver');
+      ftChallenger = await root.createSubAccount('ft-challenger');
+      await owner.call(ftOracle, 'set_stake_token', { stake_token: token.accountId });
+    });
+
+    it('should reject NEAR challenge stakes while a stake token is configured', async () => {
+      await expect(ftChallenger.call(ftOracle, 'submit_challenge', {
+        evaluation_id: 'eval_1',
+        counter_sources: [{ title: 'Report', url: 'https://reports.example/ft' }]
+      }, {
+        attachedDeposit: NEAR.parse('1.5').toString()
+      })).rejects.toThrow(/ft_transfer_call/);
+    });
+
+    it('should take challenge stakes in the token and keep the escrow in one asset', async () => {
+      await transfer(ftSolver, '2', { action: 'register_solver' });
+      await transfer(ftChallenger, '2', { action: 'register_solver' });
+      await transfer(user1, '1', {
+        action: 'submit_credibility_intent',
+        question: 'Did the token bridge resume withdrawals this week?',
+        required_sources: 1,
+        deadline_minutes: 60
+      });
+      const intents: any[] = await ftOracle.view('get_pending_intents');
+      const intentId = intents.find((intent) => intent.question === 'Did the token bridge resume withdrawals this week?').intent_id;
+
+      await transfer(ftSolver, '1', {
+        action: 'submit_evaluation',
+        intent_id: intentId,
+        answer: true,
+        confidence: 0.8,
+        sources: [{ title: 'Bridge Status', url: 'https://bridge.example/status' }],
+        execution_time_ms: '30000'
+      });
+      const [escrowBefore] = await ftOracle.view('get_escrow_health');
+
+      await transfer(ftChallenger, '1.5', {
+        action: 'submit_challenge',
+        evaluation_id: 'eval_1',
+        counter_sources: [{ title: 'Outage Log', url: 'https://outages.example/bridge' }]
+      });
+      const [escrowAfter] = await ftOracle.view('get_escrow_health');
+      expect(BigInt(escrowAfter) - BigInt(escrowBefore)).toBe(BigInt(NEAR.parse('1.5').toString()));
+
+      const challenges = await ftOracle.view('get_evaluation_challenges', { evaluation_id: 'eval_1' });
+      expect(challenges.length).toBe(1);
+      expect(challenges[0].challenger).toBe(ftChallenger.accountId);
+
+      // The asset cannot change under escrowed stakes
+      await expect(owner.call(ftOracle, 'set_stake_token', { stake_token: null })).rejects.toThrow();
+
+      // A tie repays both stakes in full, in the token they were staked in
+      await owner.call(ftOracle, 'settle_dispute', {
+        evaluation_id: 'eval_1',
+        challenge_id: challenges[0].challenge_id,
+        winner: 'tie'
+      });
+      expect(await ftOracle.view('get_pending_withdrawal', { account_id: ftChallenger.accountId }))
+        .toBe(NEAR.parse('1.5').toString());
+      expect(await ftOracle.view('get_pending_withdrawal', { account_id: ftSolver.accountId }))
+        .toBe(NEAR.parse('1').toString());
+    });
+  });
+
+  describe('Solver Quotes', () => {
+    let quoteSolver: NearAccount;
+
+    beforeAll(async () => {
+      quoteSolver = await root.createSubAccount('quote-solver');
+      await quoteSolver.call(oracleContract, 'register_solver', {}, {
+        attachedDeposit: NEAR.parse('2').toString()
+      });
+    });
+
+    it('should reserve quotes on high-value intents for Gold tier solvers', async () => {
+      const intentId = await openIntent('Will the high-value mainnet upgrade ship this quarter?', '6');
+
+      await expect(quoteSolver.call(oracleContract, 'submit_quote', {
+        intent_id: intentId,
+        proposed_confidence: 0.9,
+        estimated_time_ms: '60000',
+        bid_reward: '1'
+      })).rejects.toThrow(/Gold tier/);
+      expect(await oracleContract.view('get_intent_quotes', { intent_id: intentId })).toEqual([]);
+    });
+
+    it('should make the selected bid the reward and refund the rest of the stake', async () => {
+      const intentId = await openIntent('Will the refund mainnet upgrade ship this quarter?');
+      await expect(quoteSolver.call(oracleContract, 'submit_quote', {
+        intent_id: intentId,
+        proposed_confidence: 0.8,
+        estimated_time_ms: '60000',
+        bid_reward: NEAR.parse('1.5').toString()
+      })).rejects.toThrow(/exceeds intent reward/);
+      await quoteSolver.call(oracleContract, 'submit_quote', {
+        intent_id: intentId,
+        proposed_confidence: 0.8,
+        estimated_time_ms: '60000',
+        bid_reward: NEAR.parse('0.4').toString()
+      });
+
+      await expect(solver1.call(oracleContract, 'select_quote', {
+        intent_id: intentId,
+        solver: quoteSolver.accountId
+      })).rejects.toThrow(/Only the initiator/);
+
+      const [escrowBefore] = await oracleContract.view('get_escrow_health');
+      await user1.call(oracleContract, 'select_quote', { intent_id: intentId, solver: quoteSolver.accountId });
+      const [escrowAfter] = await oracleContract.view('get_escrow_health');
+
+      // The 0.6 NEAR the bid leaves over goes back to the initiator straight away
+      expect(BigInt(escrowBefore) - BigInt(escrowAfter)).toBe(BigInt(NEAR.parse('0.6').toString()));
+      const intent = await oracleContract.view('get_intent', { intent_id: intentId });
+      expect(intent.status).toBe('InProgress');
+      expect(intent.assigned_solver).toBe(quoteSolver.accountId);
+      expect(Number(intent.reward) / 1e24).toBeCloseTo(0.4, 6);
+      expect(Number(intent.stake) / 1e24).toBeCloseTo(0.4, 6);
+    });
+
+    it('should record the selection in the intent history', async () => {
+      const intentId = await openIntent('Will the history mainnet upgrade ship this quarter?');
+      await quoteSolver.call(oracleContract, 'submit_quote', {
+        intent_id: intentId,
+        proposed_confidence: 0.8,
+        estimated_time_ms: '60000',
+        bid_reward: '1'
+      });
+
+      await user1.call(oracleContract, 'select_quote', { intent_id: intentId, solver: quoteSolver.accountId });
+
+      const history = await oracleContract.view('get_intent_history', { intent_id: intentId });
+      const last = history[history.length - 1];
+      expect(last.action).toBe(`quote_selected:${quoteSolver.accountId}`);
+      expect(last.actor).toBe(user1.accountId);
+      expect(last.status_after).toBe('InProgress');
+    });
+
+    it('should not hand a quoted intent to a solver who has since lost a dispute', async () => {
+      const coolingSolver = await root.createSubAccount('cooling-quote-solver');
+      await coolingSolver.call(oracleContract, 'register_solver', {}, {
+        attachedDeposit: NEAR.parse('2').toString()
+      });
+      const quotedId = await openIntent('Will the quoted mainnet upgrade ship this quarter?');
+      await coolingSolver.call(oracleContract, 'submit_quote', {
+        intent_id: quotedId,
+        proposed_confidence: 0.8,
+        estimated_time_ms: '60000',
+        bid_reward: '1'
+      });
+
+      const disputedId = await openIntent('Will the disputed mainnet upgrade ship this quarter?');
+      const evaluationId = await coolingSolver.call(oracleContract, 'submit_evaluation', {
+        input: {
+          intent_id: disputedId,
+          answer: true,
+          confidence: 0.9,
+          sources: [{ title: 'Release Notes', url: 'https://releases.example/disputed' }],
+          execution_time_ms: '60000'
+        }
+      }, {
+        attachedDeposit: NEAR.parse('1').toString()
+      });
+      const challengeId = await challenger.call(oracleContract, 'submit_challenge', {
+        evaluation_id: evaluationId,
+        counter_sources: [{ title: 'Delay Notice', url: 'https://delays.example/disputed' }]
+      }, {
+        attachedDeposit: NEAR.parse('1.5').toString()
+      });
+      await owner.call(oracleContract, 'settle_dispute', {
+        evaluation_id: evaluationId,
+        challenge_id: challengeId,
+        winner: 'challenger'
+      });
+
+      await expect(user1.call(oracleContract, 'select_quote', {
+        intent_id: quotedId,
+        solver: coolingSolver.accountId
+      })).rejects.toThrow(/cooldown/);
+      await expect(coolingSolver.call(oracleContract, 'submit_quote', {
+        intent_id: await openIntent('Will the cooling mainnet upgrade ship this quarter?'),
+        proposed_confidence: 0.8,
+        estimated_time_ms: '60000',
+        bid_reward: '1'
+      })).rejects.toThrow(/cooldown/);
+      expect((await oracleContract.view('get_intent', { intent_id: quotedId })).status).toBe('Pending');
+    });
+  });
+
+  describe('Solver Stake Withdrawal', () => {
+    it('should keep stake locked while an evaluation is open to challenge', async () => {
+      const busySolver = await root.createSubAccount('busy-withdraw-solver');
+      await busySolver.call(oracleContract, 'register_solver', {}, {
+        attachedDeposit: NEAR.parse('3').toString()
+      });
+      const intentId = await openIntent('Did the exchange resume deposits after maintenance?');
+      await busySolver.call(oracleContract, 'submit_evaluation', {
+        input: {
+          intent_id: intentId,
+          answer: true,
+          confidence: 0.8,
+          sources: [{ title: 'Status Page', url: 'https://status.example/deposits' }],
+          execution_time_ms: '30000'
+        }
+      }, {
+        attachedDeposit: NEAR.parse('1').toString()
+      });
+
+      await expect(busySolver.call(oracleContract, 'withdraw_solver_stake', {
+        amount: NEAR.parse('1').toString()
+      })).rejects.toThrow(/open to challenge/);
+    });
+
+    it('should keep stake locked until a won dispute can no longer be appealed', async () => {
+      const winningSolver = await root.createSubAccount('appeal-window-solver');
+      await winningSolver.call(oracleContract, 'register_solver', {}, {
+        attachedDeposit: NEAR.parse('2').toString()
+      });
+      await settleEvaluation(winningSolver, 'evaluator', 'pair-listing');
+
+      // A reversal on appeal would claw the winnings back from this stake
+      await expect(winningSolver.call(oracleContract, 'withdraw_solver_stake', {
+        amount: NEAR.parse('2').toString()
+      })).rejects.toThrow(/can still be appealed/);
+
+      // Past the 24 hour appeal period the stake is free again
+      await worker.provider.fastForward(100_000);
+      await winningSolver.call(oracleContract, 'withdraw_solver_stake', {
+        amount: NEAR.parse('2').toString()
+      });
+      expect((await oracleContract.view('get_solver', { solver_id: winningSolver.accountId })).total_stake).toBe(0);
+    });
+
+    it('should deregister a solver who withdraws the full stake', async () => {
+      const leavingSolver = await root.createSubAccount('leaving-solver');
+      await leavingSolver.call(oracleContract, 'register_solver', {}, {
+        attachedDeposit: NEAR.parse('3').toString()
+      });
+
+      // A partial withdrawal may not leave less than the minimum stake
+      await expect(leavingSolver.call(oracleContract, 'withdraw_solver_stake', {
+        amount: NEAR.parse('2.5').toString()
+      })).rejects.toThrow(/below minimum/);
+
+      await leavingSolver.call(oracleContract, 'withdraw_solver_stake', {
+        amount: NEAR.parse('3').toString()
+      });
+      const solver = await oracleContract.view('get_solver', { solver_id: leavingSolver.accountId });
+      expect(solver.is_active).toBe(false);
+      expect(solver.total_stake).toBe(0);
+    });
+
+    it('should point a staked solver to add_solver_stake and keep a returning solver\'s record', async () => {
+      const returningSolver = await root.createSubAccount('returning-solver');
+      await returningSolver.call(oracleContract, 'register_solver', {}, {
+        attachedDeposit: NEAR.parse('2').toString()
+      });
+      await expect(returningSolver.call(oracleContract, 'register_solver', {}, {
+        attachedDeposit: NEAR.parse('2').toString()
+      })).rejects.toThrow(/add_solver_stake/);
+
+      await returningSolver.call(oracleContract, 'update_solver_specialization', {
+        specialization_areas: ['financial']
+      });
+      const before = await oracleContract.view('get_solver', { solver_id: returningSolver.accountId });
+      await returningSolver.call(oracleContract, 'withdraw_solver_stake', {
+        amount: NEAR.parse('2').toString()
+      });
+      await returningSolver.call(oracleContract, 'register_solver', {}, {
+        attachedDeposit: NEAR.parse('2').toString()
+      });
+
+      const after = await oracleContract.view('get_solver', { solver_id: returningSolver.accountId });
+      expect(after.is_active).toBe(true);
+      expect(Number(after.total_stake) / 1e24).toBeCloseTo(2, 6);
+      expect(after.reputation_window_start).toBe(before.reputation_window_start);
+      expect(after.performance_metrics.specialization_areas).toEqual(['financial']);
+    });
+  });
+
+  describe('Intent Ownership Transfer', () => {
+    it('should move the right to refunds to the new initiator', async () => {
+      const intentId = await user1.call(oracleContract, 'submit_credibility_intent', {
+        input: {
+          question: 'Will the rollup publish its fraud proof window change?',
+          required_sources: 1,
+          deadline_minutes: 60
+        }
+      }, {
+        attachedDeposit: NEAR.parse('1').toString()
+      });
+
+      await expect(solver2.call(oracleContract, 'transfer_intent_ownership', {
+        intent_id: intentId,
+        new_initiator: solver2.accountId
+      })).rejects.toThrow(/Only the initiator/);
+      await expect(user1.call(oracleContract, 'transfer_intent_ownership', {
+        intent_id: intentId,
+        new_initiator: user1.accountId
+      })).rejects.toThrow(/already belongs/);
+
+      await user1.call(oracleContract, 'transfer_intent_ownership', {
+        intent_id: intentId,
+        new_initiator: solver2.accountId
+      });
+      const intent = await oracleContract.view('get_intent', { intent_id: intentId });
+      expect(intent.initiator).toBe(solver2.accountId);
+      const history = await oracleContract.view('get_intent_history', { intent_id: intentId });
+      expect(history[history.length - 1].action).toBe(`ownership_transferred:${solver2.accountId}`);
+
+      // The previous initiator lost the refund; the new one collects the full stake
+      await expect(user1.call(oracleContract, 'cancel_intent', { intent_id: intentId })).rejects.toThrow();
+      const [escrowBefore] = await oracleContract.view('get_escrow_health');
+      const balanceBefore = (await solver2.balance()).total;
+      await solver2.call(oracleContract, 'cancel_intent', { intent_id: intentId });
+      const [escrowAfter] = await oracleContract.view('get_escrow_health');
+      const balanceAfter = (await solver2.balance()).total;
+
+      expect(BigInt(escrowBefore) - BigInt(escrowAfter)).toBe(BigInt(NEAR.parse('1').toString()));
+      // Less the gas the cancellation cost
+      expect(Number(balanceAfter.sub(balanceBefore).toString()) / 1e24).toBeGreaterThan(0.99);
+
+      // A closed intent stays with whoever held it
+      await expect(solver2.call(oracleContract, 'transfer_intent_ownership', {
+        intent_id: intentId,
+        new_initiator: user1.accountId
+      })).rejects.toThrow(/pending or in-progress/);
+    });
+  });
+
+  describe('Duplicate Intent Links', () => {
+    it('should reopen duplicates when their canonical intent is cancelled', async () => {
+      const question = 'Did the city council approve the transit levy?';
+      const canonicalId = await openIntent(question);
+      const duplicateId = await openIntent(question);
+      await owner.call(oracleContract, 'link_duplicate_intent', {
+        intent_id: duplicateId,
+        canonical_intent_id: canonicalId
+      });
+
+      await user1.call(oracleContract, 'cancel_intent', { intent_id: canonicalId });
+
+      await expect(owner.call(oracleContract, 'unlink_duplicate_intent', { intent_id: duplicateId }))
+        .rejects.toThrow(/not linked/);
+      const history = await oracleContract.view('get_intent_history', { intent_id: canonicalId });
+      expect(history[history.length - 1].action).toBe('cancelled');
+    });
+
+    it('should not link to a canonical intent whose evaluation is already confirmed', async () => {
+      const linkSolver = await root.createSubAccount('link-canonical-solver');
+      await linkSolver.call(oracleContract, 'register_solver', {}, {
+        attachedDeposit: NEAR.parse('2').toString()
+      });
+      const question = 'Did the observatory confirm the comet sighting?';
+      const canonicalId = await openIntent(question);
+      const evaluationId = await linkSolver.call(oracleContract, 'submit_evaluation', {
+        input: {
+          intent_id: canonicalId,
+          answer: true,
+          confidence: 0.9,
+          sources: [{ title: 'Observatory Bulletin', url: 'https://observatory.example/comet' }],
+          execution_time_ms: '30000'
+        }
+      }, {
+        attachedDeposit: NEAR.parse('1').toString()
+      });
+      await worker.provider.fastForward(100_000);
+      await oracleContract.call(oracleContract, 'finalize_evaluation_reward', { evaluation_id: evaluationId });
+
+      const duplicateId = await openIntent(question);
+      await expect(owner.call(oracleContract, 'link_duplicate_intent', {
+        intent_id: duplicateId,
+        canonical_intent_id: canonicalId
+      })).rejects.toThrow(/already resolved/);
+    });
+  });
+
+  describe('Dispute Appeals', () => {
+    const disputedEvaluation = async (name: string) => {
+      const solver = await root.createSubAccount(name);
+      await solver.call(oracleContract, 'register_solver', {}, {
+        attachedDeposit: NEAR.parse('2').toString()
+      });
+      const intentId = await openIntent(`Did the ${name} shipment clear customs?`);
+      const evaluationId: string = await solver.call(oracleContract, 'submit_evaluation', {
+        input: {
+          intent_id: intentId,
+          answer: true,
+          confidence: 0.9,
+          sources: [{ title: 'Customs Tracker', url: `https://customs.example/${name}` }],
+          execution_time_ms: '30000'
+        }
+      }, {
+        attachedDeposit: NEAR.parse('1').toString()
+      });
+      return { solver, evaluationId };
+    };
+
+    it('should not let one challenge of a joint settlement be appealed on its own', async () => {
+      const { solver, evaluationId } = await disputedEvaluation('joint-appeal-solver');
+      const secondChallenger = await root.createSubAccount('joint-appeal-challenger');
+      await secondChallenger.call(oracleContract, 'register_solver', {}, {
+        attachedDeposit: NEAR.parse('3').toString()
+      });
+      const challengeId = await challenger.call(oracleContract, 'submit_challenge', {
+        evaluation_id: evaluationId,
+        counter_sources: [{ title: 'Port Notice', url: 'https://ports.example/held' }]
+      }, {
+        attachedDeposit: NEAR.parse('1.5').toString()
+      });
+      await secondChallenger.call(oracleContract, 'submit_challenge', {
+        evaluation_id: evaluationId,
+        counter_sources: [{ title: 'Freight Desk', url: 'https://freight.example/held' }]
+      }, {
+        attachedDeposit: NEAR.parse('2').toString()
+      });
+
+      await owner.call(oracleContract, 'settle_dispute', {
+        evaluation_id: evaluationId,
+        challenge_id: challengeId,
+        winner: 'challenger'
+      });
+
+      await expect(solver.call(oracleContract, 'appeal_dispute', { challenge_id: challengeId }))
+        .rejects.toThrow(/settled together/);
+      const challenge = await oracleContract.view('get_challenge', { challenge_id: challengeId });
+      expect(challenge.status).toBe('Successful');
+    });
+
+    it('should only count appeal votes from staked verifiers', async () => {
+      const { solver, evaluationId } = await disputedEvaluation('staked-appeal-solver');
+      const challengeId = await challenger.call(oracleContract, 'submit_challenge', {
+        evaluation_id: evaluationId,
+        counter_sources: [{ title: 'Broker Memo', url: 'https://brokers.example/held' }]
+      }, {
+        attachedDeposit: NEAR.parse('1.5').toString()
+      });
+      await owner.call(oracleContract, 'settle_dispute', {
+        evaluation_id: evaluationId,
+        challenge_id: challengeId,
+        winner: 'challenger'
+      });
+      await solver.call(oracleContract, 'appeal_dispute', { challenge_id: challengeId });
+
+      // A verifier appointed by role alone has nothing at stake on the outcome
+      const appealVerifier = await root.createSubAccount('appeal-verifier');
+      await appealVerifier.call(oracleContract, 'register_user', { role: 'User' });
+      await owner.call(oracleContract, 'update_user_role', { user_id: appealVerifier.accountId, new_role: 'Verifier' });
+      await expect(appealVerifier.call(oracleContract, 'resolve_appeal', { challenge_id: challengeId, uphold: true }))
+        .rejects.toThrow(/Verifier stake is below the minimum/);
+
+      await appealVerifier.call(oracleContract, 'add_verifier_stake', {}, {
+        attachedDeposit: NEAR.parse('1').toString()
+      });
+      await appealVerifier.call(oracleContract, 'resolve_appeal', { challenge_id: challengeId, uphold: true });
+      const challenge = await oracleContract.view('get_challenge', { challenge_id: challengeId });
+      expect(challenge.status).toBe('Successful');
+    });
+  });
+
+  describe('Old Data Cleanup', () => {
+    it('should drop removed records from the network status counts', async () => {
+      // A zero-day retention sweeps every closed record, of which earlier tests left plenty
+      const stats = await oracleContract.view('get_network_stats');
+      const countOf = (counts: [string, number][], status: string) =>
+        counts.find(([candidate]) => candidate === status)![1];
+      expect(countOf(stats.intents_by_status, 'Settled')).toBeGreaterThan(0);
+
+      while (await owner.call(oracleContract, 'cleanup_old_data_resumable', { retention_days: 0, max_operations: 50 })) {
+        // keep sweeping until the cursor wraps around
+      }
+
+      const after = await oracleContract.view('get_network_stats');
+      for (const status of ['Completed', 'Settled', 'Expired', 'Cancelled']) {
+        expect(countOf(after.intents_by_status, status)).toBe(0);
+      }
+      for (const status of ['Confirmed', 'Refuted']) {
+        expect(countOf(after.evaluations_by_status, status)).toBe(0);
+      }
+      expect(countOf(after.intents_by_status, 'Pending')).toBe(countOf(stats.intents_by_status, 'Pending'));
+    });
   });
 });
\ No newline at end of file
@@ -100,10 +100,12 @@ describe('NEAR Testnet End-to-End Validation', () => {
       console.log('📝 Submitting credibility intent...');
       
       const intentResult = await user.call(oracleContract, 'submit_credibility_intent', {
-        question: 'Is the current price of Bitcoin above $45,000 USD?',
-        required_sources: 3,
-        confidence_threshold: 0.8,
-        deadline_minutes: 60
+        input: {
+          question: 'Is the current price of Bitcoin above $45,000 USD?',
+          required_sources: 3,
+          confidence_threshold: 0.8,
+          deadline_minutes: 60
+        }
       }, { attachedDeposit: INTENT_DEPOSIT });
 
       // Get the intent ID from pending intents
@@ -135,11 +137,13 @@ describe('NEAR Testnet End-to-End Validation', () => {
       ];
 
       evaluationId = await solver.call(oracleContract, 'submit_evaluation', {
-        intent_id: intentId,
-        answer: true,
-        confidence: 0.92,
-        sources: sources,
-        execution_time_ms: { '0': '35000' } // 35 seconds
+        input: {
+          intent_id: intentId,
+          answer: true,
+          confidence: 0.92,
+          sources: sources,
+          execution_time_ms: { '0': '35000' } // 35 seconds
+        }
       }, { attachedDeposit: INTENT_DEPOSIT });
 
       expect(evaluationId).toBeDefined();
@@ -186,10 +190,12 @@ describe('NEAR Testnet End-to-End Validation', () => {
 
       // Submit a new intent for challenging
       await user.call(oracleContract, 'submit_credibility_intent', {
-        question: 'Will Ethereum reach $4,000 by end of 2024?',
-        required_sources: 4,
-        confidence_threshold: 0.85,
-        deadline_minutes: 120
+        input: {
+          question: 'Will Ethereum reach $4,000 by end of 2024?',
+          required_sources: 4,
+          confidence_threshold: 0.85,
+          deadline_minutes: 120
+        }
       }, { attachedDeposit: INTENT_DEPOSIT });
 
       const intents = await oracleContract.view('get_pending_intents');
@@ -199,21 +205,24 @@ describe('NEAR Testnet End-to-End Validation', () => {
       await solver.call(oracleContract, 'accept_intent', { intent_id: challengeIntentId });
       
       const challengeEvaluationId = await solver.call(oracleContract, 'submit_evaluation', {
-        intent_id: challengeIntentId,
-        answer: false,
-        confidence: 0.7,
-        sources: [
-          { title: 'Crypto Analysis Report', url: 'https://example-analysis.com' },
-          { title: 'Market Trends Data', url: 'https://example-trends.com' },
-          { title: 'Price Prediction Model', url: 'https://example-prediction.com' },
-          { title: 'Economic Indicators', url: 'https://example-indicators.com' }
-        ],
-        execution_time_ms: { '0': '45000' }
+        input: {
+          intent_id: challengeIntentId,
+          answer: false,
+          confidence: 0.7,
+          sources: [
+            { title: 'Crypto Analysis Report', url: 'https://example-analysis.com' },
+            { title: 'Market Trends Data', url: 'https://example-trends.com' },
+            { title: 'Price Prediction Model', url: 'https://example-prediction.com' },
+            { title: 'Economic Indicators', url: 'https://example-indicators.com' }
+          ],
+          execution_time_ms: { '0': '45000' }
+        }
       }, { attachedDeposit: INTENT_DEPOSIT });
 
-      // Challenger submits challenge
+      // Challenger submits challenge (challenges require a registered solver of Silver tier or above)
       console.log('🚨 Submitting challenge...');
       
+      await challenger.call(oracleContract, 'register_solver', {}, { attachedDeposit: SOLVER_DEPOSIT });
       const challengeId = await challenger.call(oracleContract, 'submit_challenge', {
         evaluation_id: challengeEvaluationId,
         counter_sources: [
@@ -238,10 +247,12 @@ describe('NEAR Testnet End-to-End Validation', () => {
 
       const concurrentIntents = Array.from({ length: 5 }, (_, i) => 
         user.call(oracleContract, 'submit_credibility_intent', {
-          question: `Concurrent test question ${i + 1}: Is this statement true?`,
-          required_sources: 2,
-          confidence_threshold: 0.7,
-          deadline_minutes: 30
+          input: {
+            question: `Concurrent test question ${i + 1}: Is this statement true?`,
+            required_sources: 2,
+            confidence_threshold: 0.7,
+            deadline_minutes: 30
+          }
         }, { attachedDeposit: INTENT_DEPOSIT })
       );
 
@@ -264,10 +275,12 @@ describe('NEAR Testnet End-to-End Validation', () => {
 
       // Submit test intent
       await user.call(oracleContract, 'submit_credibility_intent', {
-        question: 'Data integrity test: Is data consistent?',
-        required_sources: 2,
-        confidence_threshold: 0.8,
-        deadline_minutes: 45
+        input: {
+          question: 'Data integrity test: Is data consistent?',
+          required_sources: 2,
+          confidence_threshold: 0.8,
+          deadline_minutes: 45
+        }
       }, { attachedDeposit: INTENT_DEPOSIT });
 
       // Verify storage stats updated correctly
@@ -306,10 +319,12 @@ describe('NEAR Testnet End-to-End Validation', () => {
 
       // Submit intent that should trigger WebSocket update
       await user.call(oracleContract, 'submit_credibility_intent', {
-        question: 'WebSocket test: Are real-time updates working?',
-        required_sources: 2,
-        confidence_threshold: 0.8,
-        deadline_minutes: 30
+        input: {
+          question: 'WebSocket test: Are real-time updates working?',
+          required_sources: 2,
+          confidence_threshold: 0.8,
+          deadline_minutes: 30
+        }
       }, { attachedDeposit: INTENT_DEPOSIT });
 
       // Wait for WebSocket update (with timeout)
@@ -333,10 +348,12 @@ describe('NEAR Testnet End-to-End Validation', () => {
       // Test invalid confidence threshold
       try {
         await user.call(oracleContract, 'submit_credibility_intent', {
-          question: 'Invalid confidence test',
-          required_sources: 2,
-          confidence_threshold: 1.5, // Invalid - over 1.0
-          deadline_minutes: 30
+          input: {
+            question: 'Invalid confidence test',
+            required_sources: 2,
+            confidence_threshold: 1.5, // Invalid - over 1.0
+            deadline_minutes: 30
+          }
         }, { attachedDeposit: INTENT_DEPOSIT });
         
         // Should not reach here
@@ -348,10 +365,12 @@ describe('NEAR Testnet End-to-End Validation', () => {
       // Test insufficient deposit
       try {
         await user.call(oracleContract, 'submit_credibility_intent', {
-          question: 'Insufficient deposit test',
-          required_sources: 2,
-          confidence_threshold: 0.8,
-          deadline_minutes: 30
+          input: {
+            question: 'Insufficient deposit test',
+            required_sources: 2,
+            confidence_threshold: 0.8,
+            deadline_minutes: 30
+          }
         }, { attachedDeposit: '1000' }); // Very small deposit
         
         // Should not reach here
@@ -368,10 +387,12 @@ describe('NEAR Testnet End-to-End Validation', () => {
 
       // Submit intent with very short deadline
       await user.call(oracleContract, 'submit_credibility_intent', {
-        question: 'Expiry test: Will this expire quickly?',
-        required_sources: 2,
-        confidence_threshold: 0.8,
-        deadline_minutes: 1 // Very short deadline
+        input: {
+          question: 'Expiry test: Will this expire quickly?',
+          required_sources: 2,
+          confidence_threshold: 0.8,
+          deadline_minutes: 5 // Shortest deadline the contract accepts by default
+        }
       }, { attachedDeposit: INTENT_DEPOSIT });
 
       // Wait for expiry (in real testnet this would take time)